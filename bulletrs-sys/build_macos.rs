@@ -340,4 +340,49 @@ pub fn build_macos() {
         .file("bullet3/examples/MultiThreading/b3Win32ThreadSupport.cpp")
         .file("bullet3/examples/MultiThreading/b3ThreadSupportInterface.cpp")
         .compile("pybullet");
+
+    cc::Build::new()
+        .include("bullet3/src")
+        .include("bullet3/Extras/VHACD/inc")
+        .include("bullet3/Extras/VHACD/public")
+        // VHACD ships its own copy of the hull computer, renamed to not clash with LinearMath one.
+        // Its allocator is the same as LinearMath one, so that one is linked instead.
+        .define("btConvexHullComputer", Some("vhacdConvexHullComputer"))
+        .define("btConvexHullInternal", Some("vhacdConvexHullInternal"))
+        .define("BT_USE_DOUBLE_PRECISION", None)
+        .define("NDEBUG", None)
+        .opt_level(3)
+        .cpp(true)
+        .flag("-fno-inline")
+        .warnings(false)
+
+        .file("bullet3/Extras/VHACD/src/VHACD.cpp")
+        .file("bullet3/Extras/VHACD/src/btConvexHullComputer.cpp")
+        .file("bullet3/Extras/VHACD/src/vhacdICHull.cpp")
+        .file("bullet3/Extras/VHACD/src/vhacdManifoldMesh.cpp")
+        .file("bullet3/Extras/VHACD/src/vhacdMesh.cpp")
+        .file("bullet3/Extras/VHACD/src/vhacdVolume.cpp")
+        .compile("VHACD");
+
+    cc::Build::new()
+        .include("bullet3/src")
+        .include("bullet3/Extras/VHACD/public")
+        .include("bullet3/examples")
+        .define("BT_USE_DOUBLE_PRECISION", None)
+        .define("NDEBUG", None)
+        .opt_level(3)
+        .cpp(true)
+        .flag("-fno-inline")
+        .warnings(false)
+
+        .file("shim/collision_shapes.cpp")
+        .file("shim/ray_result_callbacks.cpp")
+        .file("shim/gimpact.cpp")
+        .file("shim/convex_hull.cpp")
+        .file("shim/convex_decomposition.cpp")
+        .file("shim/triangle_mesh_export.cpp")
+        .file("shim/motion_state.cpp")
+        .file("shim/overlap_filter.cpp")
+        .file("bullet3/examples/ExampleBrowser/CollisionShape2TriangleMesh.cpp")
+        .compile("bulletrs_shim");
 }
@@ -0,0 +1,85 @@
+// C interface for collision shapes that bindgen could not handle
+// and virtual calls of btCollisionShape methods.
+// Everything here is compiled with the same defines as bullet itself,
+// so btScalar is double.
+
+#include "btBulletCollisionCommon.h"
+#include "BulletCollision/CollisionShapes/btHeightfieldTerrainShape.h"
+#include "BulletCollision/CollisionShapes/btConvexPointCloudShape.h"
+
+extern "C" {
+
+void bulletrsCollisionShape_calculateLocalInertia(btCollisionShape* shape, btScalar mass, btVector3* inertia)
+{
+	shape->calculateLocalInertia(mass, *inertia);
+}
+
+void bulletrsCollisionShape_getAabb(const btCollisionShape* shape, const btTransform* t, btVector3* aabbMin, btVector3* aabbMax)
+{
+	shape->getAabb(*t, *aabbMin, *aabbMax);
+}
+
+void bulletrsCollisionShape_setLocalScaling(btCollisionShape* shape, const btVector3* scaling)
+{
+	shape->setLocalScaling(*scaling);
+}
+
+const btVector3* bulletrsCollisionShape_getLocalScaling(const btCollisionShape* shape)
+{
+	return &shape->getLocalScaling();
+}
+
+void bulletrsCollisionShape_setMargin(btCollisionShape* shape, btScalar margin)
+{
+	shape->setMargin(margin);
+}
+
+btScalar bulletrsCollisionShape_getMargin(const btCollisionShape* shape)
+{
+	return shape->getMargin();
+}
+
+// In place destruction of bullet objects living in rust memory.
+// Bindgen links destructor bindings to deleting destructors, which would also free that memory.
+void bulletrsTriangleIndexVertexArray_destruct(btTriangleIndexVertexArray* array)
+{
+	array->~btTriangleIndexVertexArray();
+}
+
+void bulletrsBvhTriangleMeshShape_destruct(btBvhTriangleMeshShape* shape)
+{
+	shape->~btBvhTriangleMeshShape();
+}
+
+// Height data is not copied and should outlive the shape.
+// PHY_FLOAT means btScalar in this bullet version.
+btHeightfieldTerrainShape* bulletrsHeightfieldTerrainShape_new(
+	int heightStickWidth,
+	int heightStickLength,
+	const btScalar* heightfieldData,
+	btScalar minHeight,
+	btScalar maxHeight,
+	int upAxis,
+	bool flipQuadEdges)
+{
+	return new btHeightfieldTerrainShape(heightStickWidth, heightStickLength, heightfieldData, 1.0,
+										 minHeight, maxHeight, upAxis, PHY_FLOAT, flipQuadEdges);
+}
+
+void bulletrsHeightfieldTerrainShape_delete(btHeightfieldTerrainShape* shape)
+{
+	delete shape;
+}
+
+// Points are not copied and should outlive the shape.
+btConvexPointCloudShape* bulletrsConvexPointCloudShape_new(btVector3* points, int numPoints, const btVector3* localScaling)
+{
+	return new btConvexPointCloudShape(points, numPoints, *localScaling, true);
+}
+
+void bulletrsConvexPointCloudShape_delete(btConvexPointCloudShape* shape)
+{
+	delete shape;
+}
+
+}
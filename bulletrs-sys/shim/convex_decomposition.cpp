@@ -0,0 +1,45 @@
+// Approximate convex decomposition of triangle meshes with V-HACD.
+
+#include "VHACD.h"
+
+extern "C" {
+
+VHACD::IVHACD* bulletrsConvexDecomposition_compute(const double* points, int numPoints, const int* triangles, int numTriangles, int resolution, int depth, int maxVerticesPerHull)
+{
+	VHACD::IVHACD::Parameters params;
+	params.m_resolution = resolution;
+	params.m_depth = depth;
+	params.m_maxNumVerticesPerCH = maxVerticesPerHull;
+	params.m_oclAcceleration = false;
+
+	VHACD::IVHACD* decomposition = VHACD::CreateVHACD();
+	decomposition->Compute(points, 3, numPoints, triangles, 3, numTriangles, params);
+	return decomposition;
+}
+
+int bulletrsConvexDecomposition_getNumHulls(VHACD::IVHACD* decomposition)
+{
+	return decomposition->GetNConvexHulls();
+}
+
+int bulletrsConvexDecomposition_getHullNumPoints(VHACD::IVHACD* decomposition, int hull)
+{
+	VHACD::IVHACD::ConvexHull convexHull;
+	decomposition->GetConvexHull(hull, convexHull);
+	return convexHull.m_nPoints;
+}
+
+const double* bulletrsConvexDecomposition_getHullPoints(VHACD::IVHACD* decomposition, int hull)
+{
+	VHACD::IVHACD::ConvexHull convexHull;
+	decomposition->GetConvexHull(hull, convexHull);
+	return convexHull.m_points;
+}
+
+void bulletrsConvexDecomposition_delete(VHACD::IVHACD* decomposition)
+{
+	decomposition->Clean();
+	decomposition->Release();
+}
+
+}
@@ -0,0 +1,85 @@
+// Convex hull simplification and polyhedral features readback.
+
+#include "btBulletCollisionCommon.h"
+#include "BulletCollision/CollisionShapes/btShapeHull.h"
+#include "BulletCollision/CollisionShapes/btConvexPolyhedron.h"
+#include "LinearMath/btConvexHullComputer.h"
+
+extern "C" {
+
+btShapeHull* bulletrsShapeHull_new(const btConvexShape* shape)
+{
+	return new btShapeHull(shape);
+}
+
+bool bulletrsShapeHull_buildHull(btShapeHull* hull, btScalar margin)
+{
+	return hull->buildHull(margin);
+}
+
+int bulletrsShapeHull_numVertices(const btShapeHull* hull)
+{
+	return hull->numVertices();
+}
+
+const btVector3* bulletrsShapeHull_getVertexPointer(const btShapeHull* hull)
+{
+	return hull->getVertexPointer();
+}
+
+void bulletrsShapeHull_delete(btShapeHull* hull)
+{
+	delete hull;
+}
+
+btConvexHullComputer* bulletrsConvexHullComputer_new()
+{
+	return new btConvexHullComputer();
+}
+
+btScalar bulletrsConvexHullComputer_compute(btConvexHullComputer* computer, const btScalar* coords, int stride, int count, btScalar shrink, btScalar shrinkClamp)
+{
+	return computer->compute(coords, stride, count, shrink, shrinkClamp);
+}
+
+int bulletrsConvexHullComputer_getNumVertices(const btConvexHullComputer* computer)
+{
+	return computer->vertices.size();
+}
+
+const btVector3* bulletrsConvexHullComputer_getVertices(const btConvexHullComputer* computer)
+{
+	return computer->vertices.size() > 0 ? &computer->vertices[0] : 0;
+}
+
+void bulletrsConvexHullComputer_delete(btConvexHullComputer* computer)
+{
+	delete computer;
+}
+
+int bulletrsConvexPolyhedron_getNumVertices(const btConvexPolyhedron* polyhedron)
+{
+	return polyhedron->m_vertices.size();
+}
+
+const btVector3* bulletrsConvexPolyhedron_getVertex(const btConvexPolyhedron* polyhedron, int index)
+{
+	return &polyhedron->m_vertices[index];
+}
+
+int bulletrsConvexPolyhedron_getNumFaces(const btConvexPolyhedron* polyhedron)
+{
+	return polyhedron->m_faces.size();
+}
+
+int bulletrsConvexPolyhedron_getFaceNumIndices(const btConvexPolyhedron* polyhedron, int face)
+{
+	return polyhedron->m_faces[face].m_indices.size();
+}
+
+int bulletrsConvexPolyhedron_getFaceIndex(const btConvexPolyhedron* polyhedron, int face, int index)
+{
+	return polyhedron->m_faces[face].m_indices[index];
+}
+
+}
@@ -0,0 +1,27 @@
+// GImpact shapes and collision algorithm registration.
+
+#include "btBulletCollisionCommon.h"
+#include "BulletCollision/Gimpact/btGImpactShape.h"
+#include "BulletCollision/Gimpact/btGImpactCollisionAlgorithm.h"
+
+extern "C" {
+
+// Mesh interface is not copied and should outlive the shape.
+btGImpactMeshShape* bulletrsGImpactMeshShape_new(btStridingMeshInterface* meshInterface)
+{
+	btGImpactMeshShape* shape = new btGImpactMeshShape(meshInterface);
+	shape->updateBound();
+	return shape;
+}
+
+void bulletrsGImpactMeshShape_delete(btGImpactMeshShape* shape)
+{
+	delete shape;
+}
+
+void bulletrsGImpactCollisionAlgorithm_registerAlgorithm(btCollisionDispatcher* dispatcher)
+{
+	btGImpactCollisionAlgorithm::registerAlgorithm(dispatcher);
+}
+
+}
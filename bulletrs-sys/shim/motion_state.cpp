@@ -0,0 +1,99 @@
+// Motion state of every rigid body, optionally forwarding transforms to Rust.
+// Behaves as btDefaultMotionState and keeps the last transform set by bullet,
+// so it can be read without calling back into Rust.
+
+#include "btBulletDynamicsCommon.h"
+
+typedef void (*bulletrsGetWorldTransformCallback)(void* userData, btTransform* worldTrans);
+typedef void (*bulletrsSetWorldTransformCallback)(void* userData, const btTransform* worldTrans);
+
+ATTRIBUTE_ALIGNED16(class)
+bulletrsMotionState : public btMotionState
+{
+public:
+	BT_DECLARE_ALIGNED_ALLOCATOR();
+
+	btTransform m_graphicsWorldTrans;
+	btTransform m_centerOfMassOffset;
+	void* m_userData;
+	bulletrsGetWorldTransformCallback m_getWorldTransform;
+	bulletrsSetWorldTransformCallback m_setWorldTransform;
+
+	bulletrsMotionState(const btTransform& startTrans, const btTransform& centerOfMassOffset)
+		: m_graphicsWorldTrans(startTrans),
+		  m_centerOfMassOffset(centerOfMassOffset),
+		  m_userData(0),
+		  m_getWorldTransform(0),
+		  m_setWorldTransform(0)
+	{
+	}
+
+	virtual void getWorldTransform(btTransform & centerOfMassWorldTrans) const
+	{
+		if (m_getWorldTransform)
+		{
+			btTransform graphicsWorldTrans;
+			m_getWorldTransform(m_userData, &graphicsWorldTrans);
+			centerOfMassWorldTrans = graphicsWorldTrans * m_centerOfMassOffset.inverse();
+		}
+		else
+		{
+			centerOfMassWorldTrans = m_graphicsWorldTrans * m_centerOfMassOffset.inverse();
+		}
+	}
+
+	virtual void setWorldTransform(const btTransform& centerOfMassWorldTrans)
+	{
+		m_graphicsWorldTrans = centerOfMassWorldTrans * m_centerOfMassOffset;
+		if (m_setWorldTransform)
+		{
+			m_setWorldTransform(m_userData, &m_graphicsWorldTrans);
+		}
+	}
+};
+
+extern "C" {
+
+bulletrsMotionState* bulletrsMotionState_new(const btTransform* startTrans, const btTransform* centerOfMassOffset)
+{
+	return new bulletrsMotionState(*startTrans, *centerOfMassOffset);
+}
+
+void bulletrsMotionState_delete(bulletrsMotionState* motionState)
+{
+	delete motionState;
+}
+
+void bulletrsMotionState_setCallbacks(bulletrsMotionState* motionState, void* userData, bulletrsGetWorldTransformCallback getWorldTransform, bulletrsSetWorldTransformCallback setWorldTransform)
+{
+	motionState->m_userData = userData;
+	motionState->m_getWorldTransform = getWorldTransform;
+	motionState->m_setWorldTransform = setWorldTransform;
+	// Bodies not moved by bullet, such as sleeping ones, report the user's transform
+	if (getWorldTransform)
+	{
+		getWorldTransform(userData, &motionState->m_graphicsWorldTrans);
+	}
+}
+
+void bulletrsMotionState_getWorldTransform(const bulletrsMotionState* motionState, btTransform* centerOfMassWorldTrans)
+{
+	motionState->getWorldTransform(*centerOfMassWorldTrans);
+}
+
+void bulletrsMotionState_setWorldTransform(bulletrsMotionState* motionState, const btTransform* centerOfMassWorldTrans)
+{
+	motionState->setWorldTransform(*centerOfMassWorldTrans);
+}
+
+const btTransform* bulletrsMotionState_getGraphicsWorldTransform(const bulletrsMotionState* motionState)
+{
+	return &motionState->m_graphicsWorldTrans;
+}
+
+const btTransform* bulletrsMotionState_getCenterOfMassOffset(const bulletrsMotionState* motionState)
+{
+	return &motionState->m_centerOfMassOffset;
+}
+
+}
@@ -0,0 +1,82 @@
+// Overlap filter callback forwarding broadphase pairs to Rust.
+// Group and mask filtering of bullet's default filter is kept,
+// the callback is asked only about pairs passing it.
+
+#include "btBulletCollisionCommon.h"
+
+typedef bool (*bulletrsNeedBroadphaseCollisionCallback)(void* userData, const btCollisionObject* object0, const btCollisionObject* object1);
+
+struct bulletrsOverlapFilterCallback : public btOverlapFilterCallback
+{
+	void* m_userData;
+	bulletrsNeedBroadphaseCollisionCallback m_needBroadphaseCollision;
+
+	bulletrsOverlapFilterCallback(void* userData, bulletrsNeedBroadphaseCollisionCallback needBroadphaseCollision)
+		: m_userData(userData),
+		  m_needBroadphaseCollision(needBroadphaseCollision)
+	{
+	}
+
+	virtual bool needBroadphaseCollision(btBroadphaseProxy* proxy0, btBroadphaseProxy* proxy1) const
+	{
+		bool collides = (proxy0->m_collisionFilterGroup & proxy1->m_collisionFilterMask) != 0;
+		collides = collides && (proxy1->m_collisionFilterGroup & proxy0->m_collisionFilterMask);
+		if (!collides)
+		{
+			return false;
+		}
+		return m_needBroadphaseCollision(
+			m_userData,
+			static_cast<const btCollisionObject*>(proxy0->m_clientObject),
+			static_cast<const btCollisionObject*>(proxy1->m_clientObject));
+	}
+};
+
+extern "C" {
+
+bulletrsOverlapFilterCallback* bulletrsOverlapFilterCallback_new(void* userData, bulletrsNeedBroadphaseCollisionCallback needBroadphaseCollision)
+{
+	return new bulletrsOverlapFilterCallback(userData, needBroadphaseCollision);
+}
+
+void bulletrsOverlapFilterCallback_delete(bulletrsOverlapFilterCallback* callback)
+{
+	delete callback;
+}
+
+// Null callback restores bullet's default filtering.
+void bulletrsCollisionWorld_setOverlapFilterCallback(btCollisionWorld* world, btOverlapFilterCallback* callback)
+{
+	world->getPairCache()->setOverlapFilterCallback(callback);
+}
+
+// Recreate broadphase proxy of the object with the new group and mask,
+// pairs of the object are dropped and found again with the new filter.
+void bulletrsCollisionWorld_setCollisionFilter(btCollisionWorld* world, btCollisionObject* object, int group, int mask)
+{
+	btBroadphaseInterface* broadphase = world->getBroadphase();
+	btDispatcher* dispatcher = world->getDispatcher();
+	btBroadphaseProxy* proxy = object->getBroadphaseHandle();
+	if (!proxy)
+	{
+		return;
+	}
+
+	broadphase->getOverlappingPairCache()->removeOverlappingPairsContainingProxy(proxy, dispatcher);
+	broadphase->destroyProxy(proxy, dispatcher);
+	object->setBroadphaseHandle(0);
+
+	btVector3 minAabb;
+	btVector3 maxAabb;
+	object->getCollisionShape()->getAabb(object->getWorldTransform(), minAabb, maxAabb);
+	object->setBroadphaseHandle(broadphase->createProxy(
+		minAabb,
+		maxAabb,
+		object->getCollisionShape()->getShapeType(),
+		object,
+		group,
+		mask,
+		dispatcher));
+}
+
+}
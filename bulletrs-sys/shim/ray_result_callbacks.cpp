@@ -0,0 +1,84 @@
+// Ray result callbacks that also remember which part of concave shape was hit.
+// Objects are constructed in place, in memory owned by rust.
+
+#include <new>
+
+#include "btBulletCollisionCommon.h"
+
+struct bulletrsClosestRayResultCallback : public btCollisionWorld::ClosestRayResultCallback
+{
+	int m_shapePart;
+	int m_triangleIndex;
+
+	bulletrsClosestRayResultCallback(const btVector3& rayFromWorld, const btVector3& rayToWorld)
+		: btCollisionWorld::ClosestRayResultCallback(rayFromWorld, rayToWorld),
+		  m_shapePart(-1),
+		  m_triangleIndex(-1)
+	{
+	}
+
+	virtual btScalar addSingleResult(btCollisionWorld::LocalRayResult& rayResult, bool normalInWorldSpace)
+	{
+		if (rayResult.m_localShapeInfo)
+		{
+			m_shapePart = rayResult.m_localShapeInfo->m_shapePart;
+			m_triangleIndex = rayResult.m_localShapeInfo->m_triangleIndex;
+		}
+		else
+		{
+			m_shapePart = -1;
+			m_triangleIndex = -1;
+		}
+		return btCollisionWorld::ClosestRayResultCallback::addSingleResult(rayResult, normalInWorldSpace);
+	}
+};
+
+struct bulletrsAllHitsRayResultCallback : public btCollisionWorld::AllHitsRayResultCallback
+{
+	btAlignedObjectArray<int> m_shapeParts;
+	btAlignedObjectArray<int> m_triangleIndices;
+
+	bulletrsAllHitsRayResultCallback(const btVector3& rayFromWorld, const btVector3& rayToWorld)
+		: btCollisionWorld::AllHitsRayResultCallback(rayFromWorld, rayToWorld)
+	{
+	}
+
+	virtual btScalar addSingleResult(btCollisionWorld::LocalRayResult& rayResult, bool normalInWorldSpace)
+	{
+		if (rayResult.m_localShapeInfo)
+		{
+			m_shapeParts.push_back(rayResult.m_localShapeInfo->m_shapePart);
+			m_triangleIndices.push_back(rayResult.m_localShapeInfo->m_triangleIndex);
+		}
+		else
+		{
+			m_shapeParts.push_back(-1);
+			m_triangleIndices.push_back(-1);
+		}
+		return btCollisionWorld::AllHitsRayResultCallback::addSingleResult(rayResult, normalInWorldSpace);
+	}
+};
+
+extern "C" {
+
+void bulletrsClosestRayResultCallback_construct(bulletrsClosestRayResultCallback* self, const btVector3* rayFromWorld, const btVector3* rayToWorld)
+{
+	new (self) bulletrsClosestRayResultCallback(*rayFromWorld, *rayToWorld);
+}
+
+void bulletrsClosestRayResultCallback_destruct(bulletrsClosestRayResultCallback* self)
+{
+	self->~bulletrsClosestRayResultCallback();
+}
+
+void bulletrsAllHitsRayResultCallback_construct(bulletrsAllHitsRayResultCallback* self, const btVector3* rayFromWorld, const btVector3* rayToWorld)
+{
+	new (self) bulletrsAllHitsRayResultCallback(*rayFromWorld, *rayToWorld);
+}
+
+void bulletrsAllHitsRayResultCallback_destruct(bulletrsAllHitsRayResultCallback* self)
+{
+	self->~bulletrsAllHitsRayResultCallback();
+}
+
+}
@@ -0,0 +1,47 @@
+// Triangulation of any collision shape, built on the example browser's CollisionShape2TriangleMesh.
+
+#include "btBulletCollisionCommon.h"
+#include "ExampleBrowser/CollisionShape2TriangleMesh.h"
+
+struct bulletrsTriangleMeshExport
+{
+	btAlignedObjectArray<btVector3> m_vertices;
+	btAlignedObjectArray<btVector3> m_normals;
+	btAlignedObjectArray<int> m_indices;
+};
+
+extern "C" {
+
+bulletrsTriangleMeshExport* bulletrsTriangleMeshExport_new(btCollisionShape* shape, const btTransform* transform)
+{
+	bulletrsTriangleMeshExport* mesh = new bulletrsTriangleMeshExport();
+	CollisionShape2TriangleMesh(shape, *transform, mesh->m_vertices, mesh->m_normals, mesh->m_indices);
+	return mesh;
+}
+
+int bulletrsTriangleMeshExport_getNumVertices(const bulletrsTriangleMeshExport* mesh)
+{
+	return mesh->m_vertices.size();
+}
+
+const btVector3* bulletrsTriangleMeshExport_getVertex(const bulletrsTriangleMeshExport* mesh, int index)
+{
+	return &mesh->m_vertices[index];
+}
+
+int bulletrsTriangleMeshExport_getNumIndices(const bulletrsTriangleMeshExport* mesh)
+{
+	return mesh->m_indices.size();
+}
+
+int bulletrsTriangleMeshExport_getIndex(const bulletrsTriangleMeshExport* mesh, int index)
+{
+	return mesh->m_indices[index];
+}
+
+void bulletrsTriangleMeshExport_delete(bulletrsTriangleMeshExport* mesh)
+{
+	delete mesh;
+}
+
+}
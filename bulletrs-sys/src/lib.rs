@@ -12,3 +12,7 @@ mod bt_bullet_dynamics_common;
 
 #[cfg(target_arch = "x86_64")]
 pub use bt_bullet_dynamics_common::*;
+
+mod shim;
+
+pub use shim::*;
@@ -0,0 +1,412 @@
+//! Hand written bindings to `shim/*.cpp`.
+//! Covers bullet classes bindgen could not handle and rust-side subclasses of bullet classes.
+
+use super::*;
+
+extern "C" {
+    /// Virtual call of btCollisionShape::calculateLocalInertia
+    pub fn bulletrsCollisionShape_calculateLocalInertia(
+        shape: *mut btCollisionShape,
+        mass: btScalar,
+        inertia: *mut btVector3,
+    );
+}
+extern "C" {
+    /// Virtual call of btCollisionShape::getAabb
+    pub fn bulletrsCollisionShape_getAabb(
+        shape: *const btCollisionShape,
+        t: *const btTransform,
+        aabbMin: *mut btVector3,
+        aabbMax: *mut btVector3,
+    );
+}
+extern "C" {
+    /// Virtual call of btCollisionShape::setLocalScaling
+    pub fn bulletrsCollisionShape_setLocalScaling(
+        shape: *mut btCollisionShape,
+        scaling: *const btVector3,
+    );
+}
+extern "C" {
+    /// Virtual call of btCollisionShape::getLocalScaling
+    pub fn bulletrsCollisionShape_getLocalScaling(
+        shape: *const btCollisionShape,
+    ) -> *const btVector3;
+}
+extern "C" {
+    /// Virtual call of btCollisionShape::setMargin
+    pub fn bulletrsCollisionShape_setMargin(shape: *mut btCollisionShape, margin: btScalar);
+}
+extern "C" {
+    /// Virtual call of btCollisionShape::getMargin
+    pub fn bulletrsCollisionShape_getMargin(shape: *const btCollisionShape) -> btScalar;
+}
+extern "C" {
+    /// Destructor call without freeing the memory
+    pub fn bulletrsTriangleIndexVertexArray_destruct(array: *mut btTriangleIndexVertexArray);
+}
+extern "C" {
+    /// Destructor call without freeing the memory
+    pub fn bulletrsBvhTriangleMeshShape_destruct(shape: *mut btBvhTriangleMeshShape);
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct btHeightfieldTerrainShape {
+    _unused: [u8; 0],
+}
+extern "C" {
+    pub fn bulletrsHeightfieldTerrainShape_new(
+        heightStickWidth: ::std::os::raw::c_int,
+        heightStickLength: ::std::os::raw::c_int,
+        heightfieldData: *const btScalar,
+        minHeight: btScalar,
+        maxHeight: btScalar,
+        upAxis: ::std::os::raw::c_int,
+        flipQuadEdges: bool,
+    ) -> *mut btHeightfieldTerrainShape;
+}
+extern "C" {
+    pub fn bulletrsHeightfieldTerrainShape_delete(shape: *mut btHeightfieldTerrainShape);
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct btConvexPointCloudShape {
+    _unused: [u8; 0],
+}
+extern "C" {
+    pub fn bulletrsConvexPointCloudShape_new(
+        points: *mut btVector3,
+        numPoints: ::std::os::raw::c_int,
+        localScaling: *const btVector3,
+    ) -> *mut btConvexPointCloudShape;
+}
+extern "C" {
+    pub fn bulletrsConvexPointCloudShape_delete(shape: *mut btConvexPointCloudShape);
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct btGImpactMeshShape {
+    _unused: [u8; 0],
+}
+extern "C" {
+    pub fn bulletrsGImpactMeshShape_new(
+        meshInterface: *mut btStridingMeshInterface,
+    ) -> *mut btGImpactMeshShape;
+}
+extern "C" {
+    pub fn bulletrsGImpactMeshShape_delete(shape: *mut btGImpactMeshShape);
+}
+extern "C" {
+    pub fn bulletrsGImpactCollisionAlgorithm_registerAlgorithm(
+        dispatcher: *mut btCollisionDispatcher,
+    );
+}
+
+/// btCollisionWorld::ClosestRayResultCallback remembering LocalShapeInfo of the closest hit.
+#[repr(C)]
+#[derive(Debug)]
+pub struct bulletrsClosestRayResultCallback {
+    pub _base: btCollisionWorld_ClosestRayResultCallback,
+    pub m_shapePart: ::std::os::raw::c_int,
+    pub m_triangleIndex: ::std::os::raw::c_int,
+}
+extern "C" {
+    pub fn bulletrsClosestRayResultCallback_construct(
+        this: *mut bulletrsClosestRayResultCallback,
+        rayFromWorld: *const btVector3,
+        rayToWorld: *const btVector3,
+    );
+}
+extern "C" {
+    pub fn bulletrsClosestRayResultCallback_destruct(this: *mut bulletrsClosestRayResultCallback);
+}
+impl bulletrsClosestRayResultCallback {
+    #[inline]
+    pub unsafe fn new(rayFromWorld: *const btVector3, rayToWorld: *const btVector3) -> Self {
+        let mut __bindgen_tmp = ::std::mem::uninitialized();
+        bulletrsClosestRayResultCallback_construct(&mut __bindgen_tmp, rayFromWorld, rayToWorld);
+        __bindgen_tmp
+    }
+}
+
+/// btCollisionWorld::AllHitsRayResultCallback remembering LocalShapeInfo of every hit.
+#[repr(C)]
+#[derive(Debug)]
+pub struct bulletrsAllHitsRayResultCallback {
+    pub _base: btCollisionWorld_AllHitsRayResultCallback,
+    pub m_shapeParts: btAlignedObjectArray<::std::os::raw::c_int>,
+    pub m_triangleIndices: btAlignedObjectArray<::std::os::raw::c_int>,
+}
+extern "C" {
+    pub fn bulletrsAllHitsRayResultCallback_construct(
+        this: *mut bulletrsAllHitsRayResultCallback,
+        rayFromWorld: *const btVector3,
+        rayToWorld: *const btVector3,
+    );
+}
+extern "C" {
+    pub fn bulletrsAllHitsRayResultCallback_destruct(this: *mut bulletrsAllHitsRayResultCallback);
+}
+impl bulletrsAllHitsRayResultCallback {
+    #[inline]
+    pub unsafe fn new(rayFromWorld: *const btVector3, rayToWorld: *const btVector3) -> Self {
+        let mut __bindgen_tmp = ::std::mem::uninitialized();
+        bulletrsAllHitsRayResultCallback_construct(&mut __bindgen_tmp, rayFromWorld, rayToWorld);
+        __bindgen_tmp
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct btShapeHull {
+    _unused: [u8; 0],
+}
+extern "C" {
+    pub fn bulletrsShapeHull_new(shape: *const btConvexShape) -> *mut btShapeHull;
+}
+extern "C" {
+    pub fn bulletrsShapeHull_buildHull(hull: *mut btShapeHull, margin: btScalar) -> bool;
+}
+extern "C" {
+    pub fn bulletrsShapeHull_numVertices(hull: *const btShapeHull) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn bulletrsShapeHull_getVertexPointer(hull: *const btShapeHull) -> *const btVector3;
+}
+extern "C" {
+    pub fn bulletrsShapeHull_delete(hull: *mut btShapeHull);
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct btConvexHullComputer {
+    _unused: [u8; 0],
+}
+extern "C" {
+    pub fn bulletrsConvexHullComputer_new() -> *mut btConvexHullComputer;
+}
+extern "C" {
+    pub fn bulletrsConvexHullComputer_compute(
+        computer: *mut btConvexHullComputer,
+        coords: *const btScalar,
+        stride: ::std::os::raw::c_int,
+        count: ::std::os::raw::c_int,
+        shrink: btScalar,
+        shrinkClamp: btScalar,
+    ) -> btScalar;
+}
+extern "C" {
+    pub fn bulletrsConvexHullComputer_getNumVertices(
+        computer: *const btConvexHullComputer,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn bulletrsConvexHullComputer_getVertices(
+        computer: *const btConvexHullComputer,
+    ) -> *const btVector3;
+}
+extern "C" {
+    pub fn bulletrsConvexHullComputer_delete(computer: *mut btConvexHullComputer);
+}
+
+extern "C" {
+    pub fn bulletrsConvexPolyhedron_getNumVertices(
+        polyhedron: *const btConvexPolyhedron,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn bulletrsConvexPolyhedron_getVertex(
+        polyhedron: *const btConvexPolyhedron,
+        index: ::std::os::raw::c_int,
+    ) -> *const btVector3;
+}
+extern "C" {
+    pub fn bulletrsConvexPolyhedron_getNumFaces(
+        polyhedron: *const btConvexPolyhedron,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn bulletrsConvexPolyhedron_getFaceNumIndices(
+        polyhedron: *const btConvexPolyhedron,
+        face: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn bulletrsConvexPolyhedron_getFaceIndex(
+        polyhedron: *const btConvexPolyhedron,
+        face: ::std::os::raw::c_int,
+        index: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+
+/// V-HACD decomposition results, VHACD::IVHACD on the C++ side.
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct bulletrsConvexDecomposition {
+    _unused: [u8; 0],
+}
+extern "C" {
+    pub fn bulletrsConvexDecomposition_compute(
+        points: *const f64,
+        numPoints: ::std::os::raw::c_int,
+        triangles: *const ::std::os::raw::c_int,
+        numTriangles: ::std::os::raw::c_int,
+        resolution: ::std::os::raw::c_int,
+        depth: ::std::os::raw::c_int,
+        maxVerticesPerHull: ::std::os::raw::c_int,
+    ) -> *mut bulletrsConvexDecomposition;
+}
+extern "C" {
+    pub fn bulletrsConvexDecomposition_getNumHulls(
+        decomposition: *mut bulletrsConvexDecomposition,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn bulletrsConvexDecomposition_getHullNumPoints(
+        decomposition: *mut bulletrsConvexDecomposition,
+        hull: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn bulletrsConvexDecomposition_getHullPoints(
+        decomposition: *mut bulletrsConvexDecomposition,
+        hull: ::std::os::raw::c_int,
+    ) -> *const f64;
+}
+extern "C" {
+    pub fn bulletrsConvexDecomposition_delete(decomposition: *mut bulletrsConvexDecomposition);
+}
+
+/// Vertices and indices produced by CollisionShape2TriangleMesh.
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct bulletrsTriangleMeshExport {
+    _unused: [u8; 0],
+}
+extern "C" {
+    pub fn bulletrsTriangleMeshExport_new(
+        shape: *mut btCollisionShape,
+        transform: *const btTransform,
+    ) -> *mut bulletrsTriangleMeshExport;
+}
+extern "C" {
+    pub fn bulletrsTriangleMeshExport_getNumVertices(
+        mesh: *const bulletrsTriangleMeshExport,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn bulletrsTriangleMeshExport_getVertex(
+        mesh: *const bulletrsTriangleMeshExport,
+        index: ::std::os::raw::c_int,
+    ) -> *const btVector3;
+}
+extern "C" {
+    pub fn bulletrsTriangleMeshExport_getNumIndices(
+        mesh: *const bulletrsTriangleMeshExport,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn bulletrsTriangleMeshExport_getIndex(
+        mesh: *const bulletrsTriangleMeshExport,
+        index: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn bulletrsTriangleMeshExport_delete(mesh: *mut bulletrsTriangleMeshExport);
+}
+
+/// btMotionState keeping the last transform like btDefaultMotionState,
+/// optionally forwarding transforms to user callbacks.
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct bulletrsMotionState {
+    _unused: [u8; 0],
+}
+pub type bulletrsGetWorldTransformCallback = ::std::option::Option<
+    unsafe extern "C" fn(userData: *mut ::std::os::raw::c_void, worldTrans: *mut btTransform),
+>;
+pub type bulletrsSetWorldTransformCallback = ::std::option::Option<
+    unsafe extern "C" fn(userData: *mut ::std::os::raw::c_void, worldTrans: *const btTransform),
+>;
+extern "C" {
+    pub fn bulletrsMotionState_new(
+        startTrans: *const btTransform,
+        centerOfMassOffset: *const btTransform,
+    ) -> *mut bulletrsMotionState;
+}
+extern "C" {
+    pub fn bulletrsMotionState_delete(motionState: *mut bulletrsMotionState);
+}
+extern "C" {
+    /// Graphics transform is initialized from getWorldTransform callback
+    pub fn bulletrsMotionState_setCallbacks(
+        motionState: *mut bulletrsMotionState,
+        userData: *mut ::std::os::raw::c_void,
+        getWorldTransform: bulletrsGetWorldTransformCallback,
+        setWorldTransform: bulletrsSetWorldTransformCallback,
+    );
+}
+extern "C" {
+    pub fn bulletrsMotionState_getWorldTransform(
+        motionState: *const bulletrsMotionState,
+        centerOfMassWorldTrans: *mut btTransform,
+    );
+}
+extern "C" {
+    pub fn bulletrsMotionState_setWorldTransform(
+        motionState: *mut bulletrsMotionState,
+        centerOfMassWorldTrans: *const btTransform,
+    );
+}
+extern "C" {
+    pub fn bulletrsMotionState_getGraphicsWorldTransform(
+        motionState: *const bulletrsMotionState,
+    ) -> *const btTransform;
+}
+extern "C" {
+    pub fn bulletrsMotionState_getCenterOfMassOffset(
+        motionState: *const bulletrsMotionState,
+    ) -> *const btTransform;
+}
+
+/// btOverlapFilterCallback keeping group and mask filtering,
+/// pairs passing it are forwarded to user callback.
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct bulletrsOverlapFilterCallback {
+    _unused: [u8; 0],
+}
+pub type bulletrsNeedBroadphaseCollisionCallback = ::std::option::Option<
+    unsafe extern "C" fn(
+        userData: *mut ::std::os::raw::c_void,
+        object0: *const btCollisionObject,
+        object1: *const btCollisionObject,
+    ) -> bool,
+>;
+extern "C" {
+    pub fn bulletrsOverlapFilterCallback_new(
+        userData: *mut ::std::os::raw::c_void,
+        needBroadphaseCollision: bulletrsNeedBroadphaseCollisionCallback,
+    ) -> *mut bulletrsOverlapFilterCallback;
+}
+extern "C" {
+    pub fn bulletrsOverlapFilterCallback_delete(callback: *mut bulletrsOverlapFilterCallback);
+}
+extern "C" {
+    pub fn bulletrsCollisionWorld_setOverlapFilterCallback(
+        world: *mut btCollisionWorld,
+        callback: *mut btOverlapFilterCallback,
+    );
+}
+extern "C" {
+    /// Recreate the broadphase proxy of a collision object with new group and mask.
+    pub fn bulletrsCollisionWorld_setCollisionFilter(
+        world: *mut btCollisionWorld,
+        object: *mut btCollisionObject,
+        group: ::std::os::raw::c_int,
+        mask: ::std::os::raw::c_int,
+    );
+}
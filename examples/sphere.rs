@@ -25,7 +25,7 @@ fn main() {
         Vector3::new(0.0, 0.0, 0.0),
         Vector4::new(0.0, 0.0, 0.0, 1.0),
     ));
-    ground_rigid_body.set_restitution(0.95);
+    ground_rigid_body.set_restitution(0.95).unwrap();
 
     let fall_shape = Shape::new_sphere(2.0);
     let mass = 0.1;
@@ -36,10 +36,10 @@ fn main() {
         Vector3::new(0.0, 5.0, 0.0),
         Vector4::new(0.0, 0.0, 0.0, 1.0),
     ));
-    fall_rigid_body.set_restitution(0.9);
+    fall_rigid_body.set_restitution(0.9).unwrap();
 
     for _ in 0..100 {
         dynamics_world.step_simulation(0.1, 0, 0.0);
-        println!("{:?}", fall_rigid_body.get_world_position_and_orientation());
+        println!("{:?}", fall_rigid_body.get_world_position_and_orientation().unwrap());
     }
 }
@@ -0,0 +1,60 @@
+/// Newtype over bullet's i32 flags with set operations.
+/// Constants of the flags are declared in a separate impl block of the type.
+macro_rules! bit_flags {
+    ($(#[$attr:meta])* pub struct $name:ident(i32);) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct $name(i32);
+
+        impl $name {
+            /// Flags from raw bullet bits.
+            pub fn from_bits(bits: i32) -> $name {
+                $name(bits)
+            }
+
+            pub fn empty() -> $name {
+                $name(0)
+            }
+
+            pub fn bits(&self) -> i32 {
+                self.0
+            }
+
+            pub fn contains(&self, other: $name) -> bool {
+                self.0 & other.0 == other.0
+            }
+
+            pub fn insert(&mut self, other: $name) {
+                self.0 |= other.0;
+            }
+
+            pub fn remove(&mut self, other: $name) {
+                self.0 &= !other.0;
+            }
+        }
+
+        impl ::std::ops::BitOr for $name {
+            type Output = $name;
+
+            fn bitor(self, other: $name) -> $name {
+                $name(self.0 | other.0)
+            }
+        }
+
+        impl ::std::ops::BitAnd for $name {
+            type Output = $name;
+
+            fn bitand(self, other: $name) -> $name {
+                $name(self.0 & other.0)
+            }
+        }
+
+        impl ::std::ops::Not for $name {
+            type Output = $name;
+
+            fn not(self) -> $name {
+                $name(!self.0)
+            }
+        }
+    };
+}
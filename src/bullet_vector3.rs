@@ -1,5 +1,7 @@
 use mint::{Vector3, Vector4};
 
+/// Same layout and alignment as btVector3, so buffers of it may be given to bullet.
+#[repr(C, align(16))]
 pub struct BulletVector3(pub [f64; 4]);
 
 impl Into<BulletVector3> for Vector3<f64> {
@@ -28,6 +28,24 @@ impl Broadphase {
         }
     }
 
+    /// Remove cached pairs of the proxy, so they are found again with new filters.
+    pub(crate) unsafe fn clean_proxy_from_pairs(
+        &self,
+        proxy: *mut sys::btBroadphaseProxy,
+        dispatcher: *mut sys::btDispatcher,
+    ) {
+        match self {
+            // dbvt broadphase created without pair cache makes hashed one
+            &Broadphase::DbvtBroadphase(ref broadphase) => {
+                sys::btHashedOverlappingPairCache_cleanProxyFromPairs(
+                    broadphase.m_paircache as *mut _,
+                    proxy,
+                    dispatcher,
+                );
+            }
+        }
+    }
+
 }
 impl Drop for Broadphase {
     fn drop(&mut self) {
@@ -39,3 +57,29 @@ impl Drop for Broadphase {
         }
     }
 }
+
+bit_flags! {
+    /// Collision filter groups. Two bodies collide when group of each one
+    /// is in the mask of the other one.
+    /// Bits above CHARACTER are not used by bullet and may be used for custom groups.
+    pub struct CollisionGroup(i32);
+}
+
+impl CollisionGroup {
+    /// Group of dynamic bodies added without filter.
+    pub const DEFAULT: CollisionGroup =
+        CollisionGroup(sys::btBroadphaseProxy_CollisionFilterGroups_DefaultFilter as i32);
+    /// Group of static bodies added without filter.
+    pub const STATIC: CollisionGroup =
+        CollisionGroup(sys::btBroadphaseProxy_CollisionFilterGroups_StaticFilter as i32);
+    pub const KINEMATIC: CollisionGroup =
+        CollisionGroup(sys::btBroadphaseProxy_CollisionFilterGroups_KinematicFilter as i32);
+    pub const DEBRIS: CollisionGroup =
+        CollisionGroup(sys::btBroadphaseProxy_CollisionFilterGroups_DebrisFilter as i32);
+    pub const SENSOR_TRIGGER: CollisionGroup =
+        CollisionGroup(sys::btBroadphaseProxy_CollisionFilterGroups_SensorTrigger as i32);
+    pub const CHARACTER: CollisionGroup =
+        CollisionGroup(sys::btBroadphaseProxy_CollisionFilterGroups_CharacterFilter as i32);
+    pub const ALL: CollisionGroup =
+        CollisionGroup(sys::btBroadphaseProxy_CollisionFilterGroups_AllFilter as i32);
+}
@@ -0,0 +1,162 @@
+use sys;
+use bullet_vector3::BulletVector3;
+use mint::Vector3;
+
+use super::convex_hull;
+
+/// Parameters of Shape::new_convex_decomposition.
+pub struct ConvexDecompositionParams {
+    /// Number of voxels the mesh is sampled with. Higher is more precise and slower.
+    pub resolution: u32,
+    /// Maximum number of convex hulls, neighbouring hulls are merged to fit.
+    pub max_hulls: usize,
+    /// Maximum number of vertices of each convex hull, including hulls merged to fit max_hulls.
+    pub max_vertices_per_hull: usize,
+}
+
+impl Default for ConvexDecompositionParams {
+    fn default() -> ConvexDecompositionParams {
+        ConvexDecompositionParams {
+            resolution: 100000,
+            max_hulls: 32,
+            max_vertices_per_hull: 64,
+        }
+    }
+}
+
+/// Split a triangle mesh into convex hulls with V-HACD.
+/// Returns points of every hull, in mesh's space.
+pub(crate) fn decompose<T: Into<Vector3<f64>> + Clone>(
+    vertices: &[T],
+    indices: &[u32],
+    params: &ConvexDecompositionParams,
+) -> Vec<Vec<BulletVector3>> {
+    assert!(indices.len() % 3 == 0, "Indices count should be multiple of 3");
+    assert!(params.max_hulls > 0, "At least one convex hull is required");
+
+    let points = vertices
+        .iter()
+        .flat_map(|vertex| {
+            let vertex: BulletVector3 = vertex.clone().into().into();
+            vertex.0[0..3].to_vec()
+        })
+        .collect::<Vec<f64>>();
+    let triangles = indices.iter().map(|index| *index as i32).collect::<Vec<i32>>();
+
+    // Every V-HACD clipping stage splits each part in two.
+    let mut depth = 0;
+    while (1 << depth) < params.max_hulls && depth < 31 {
+        depth += 1;
+    }
+
+    let mut hulls = unsafe {
+        let decomposition = sys::bulletrsConvexDecomposition_compute(
+            points.as_ptr(),
+            vertices.len() as i32,
+            triangles.as_ptr(),
+            triangles.len() as i32 / 3,
+            params.resolution as i32,
+            depth,
+            params.max_vertices_per_hull as i32,
+        );
+        let hulls = (0..sys::bulletrsConvexDecomposition_getNumHulls(decomposition))
+            .map(|hull| {
+                let points = sys::bulletrsConvexDecomposition_getHullPoints(decomposition, hull);
+                let count = sys::bulletrsConvexDecomposition_getHullNumPoints(decomposition, hull);
+                (0..count as isize)
+                    .map(|index| {
+                        let point = points.offset(index * 3);
+                        BulletVector3([*point, *point.offset(1), *point.offset(2), 0.0])
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        sys::bulletrsConvexDecomposition_delete(decomposition);
+        hulls
+    };
+
+    while hulls.len() > params.max_hulls {
+        let (a, b) = closest_hulls(&hulls);
+        let removed = hulls.swap_remove(b);
+        let mut points = hulls.swap_remove(a);
+        points.extend(removed);
+        let points = convex_hull::hull_computer_points(&points, 0.0, 0.0);
+        hulls.push(limit_vertices(points, params.max_vertices_per_hull));
+    }
+
+    hulls
+}
+
+/// Reduce hull to at most `max_vertices` of its support points,
+/// sampled in directions evenly spread over a sphere.
+fn limit_vertices(points: Vec<BulletVector3>, max_vertices: usize) -> Vec<BulletVector3> {
+    if points.len() <= max_vertices {
+        return points;
+    }
+
+    let golden_angle = ::std::f64::consts::PI * (3.0 - 5.0f64.sqrt());
+    let mut directions = max_vertices;
+    let mut support = vec![];
+    while support.len() < max_vertices && directions <= points.len() * 16 {
+        support.clear();
+        for i in 0..directions {
+            let y = 1.0 - 2.0 * (i as f64 + 0.5) / directions as f64;
+            let radius = (1.0 - y * y).sqrt();
+            let angle = golden_angle * i as f64;
+            let direction = [angle.cos() * radius, y, angle.sin() * radius];
+
+            let (index, _) = points
+                .iter()
+                .map(|point| (0..3).map(|i| point.0[i] * direction[i]).sum::<f64>())
+                .enumerate()
+                .fold((0, ::std::f64::MIN), |best, (index, distance)| {
+                    if distance > best.1 {
+                        (index, distance)
+                    } else {
+                        best
+                    }
+                });
+            if !support.contains(&index) && support.len() < max_vertices {
+                support.push(index);
+            }
+        }
+        directions *= 2;
+    }
+
+    let points = support
+        .iter()
+        .map(|&index| BulletVector3(points[index].0))
+        .collect::<Vec<_>>();
+    convex_hull::hull_computer_points(&points, 0.0, 0.0)
+}
+
+/// Indices of the two hulls with the closest centers, second index is bigger.
+fn closest_hulls(hulls: &[Vec<BulletVector3>]) -> (usize, usize) {
+    let centers = hulls
+        .iter()
+        .map(|points| {
+            let mut center = [0.0; 3];
+            for point in points.iter() {
+                for i in 0..3 {
+                    center[i] += point.0[i] / points.len() as f64;
+                }
+            }
+            center
+        })
+        .collect::<Vec<_>>();
+
+    let mut closest = (0, 1);
+    let mut closest_distance = ::std::f64::MAX;
+    for a in 0..centers.len() {
+        for b in a + 1..centers.len() {
+            let distance = (0..3)
+                .map(|i| (centers[a][i] - centers[b][i]).powi(2))
+                .sum::<f64>();
+            if distance < closest_distance {
+                closest = (a, b);
+                closest_distance = distance;
+            }
+        }
+    }
+    closest
+}
@@ -0,0 +1,124 @@
+use sys;
+use bullet_vector3::BulletVector3;
+use mint::Vector3;
+
+/// Point set reduction used by Shape::new_convex_hull_with_options.
+pub enum HullOptimization {
+    /// Keep every given point.
+    None,
+    /// btShapeHull, approximates the hull by sampling its support vertices
+    /// in a fixed set of directions. Gives at most 42 vertices.
+    ShapeHull,
+    /// btConvexHullComputer, exact hull without interior and coplanar points.
+    /// Faces are moved inwards by `shrink`, but not more than `shrink_clamp` times
+    /// the distance from the hull's center to its closest face.
+    HullComputer { shrink: f64, shrink_clamp: f64 },
+}
+
+pub struct ConvexHullOptions {
+    pub optimization: HullOptimization,
+    /// Compute faces and edges with initializePolyhedralFeatures.
+    /// Used by bullet for clipping based contact generation and needed by Shape::convex_polyhedron.
+    pub polyhedral_features: bool,
+}
+
+impl Default for ConvexHullOptions {
+    fn default() -> ConvexHullOptions {
+        ConvexHullOptions {
+            optimization: HullOptimization::None,
+            polyhedral_features: false,
+        }
+    }
+}
+
+/// Vertices, faces and edges of a polyhedral shape, in shape's local space with scaling applied.
+/// Faces are lists of vertex indices, edges are (smaller, bigger) vertex index pairs.
+#[derive(Debug, Clone)]
+pub struct ConvexPolyhedron {
+    pub vertices: Vec<Vector3<f64>>,
+    pub faces: Vec<Vec<u32>>,
+    pub edges: Vec<(u32, u32)>,
+}
+
+impl ConvexPolyhedron {
+    pub(crate) unsafe fn from_ptr(polyhedron: *const sys::btConvexPolyhedron) -> ConvexPolyhedron {
+        let vertices = (0..sys::bulletrsConvexPolyhedron_getNumVertices(polyhedron))
+            .map(|index| {
+                let vertex = &*sys::bulletrsConvexPolyhedron_getVertex(polyhedron, index);
+                ::bullet_vector3::vector_from_slice(&vertex.m_floats[0..3])
+            })
+            .collect::<Vec<_>>();
+
+        let faces = (0..sys::bulletrsConvexPolyhedron_getNumFaces(polyhedron))
+            .map(|face| {
+                (0..sys::bulletrsConvexPolyhedron_getFaceNumIndices(polyhedron, face))
+                    .map(|index| {
+                        sys::bulletrsConvexPolyhedron_getFaceIndex(polyhedron, face, index) as u32
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut edges = vec![];
+        for face in faces.iter() {
+            for (i, a) in face.iter().enumerate() {
+                let b = face[(i + 1) % face.len()];
+                edges.push((*a.min(&b), *a.max(&b)));
+            }
+        }
+        edges.sort();
+        edges.dedup();
+
+        ConvexPolyhedron {
+            vertices,
+            faces,
+            edges,
+        }
+    }
+}
+
+pub(crate) fn shape_hull_points(
+    shape: *const sys::btConvexShape,
+    margin: f64,
+) -> Vec<BulletVector3> {
+    unsafe {
+        let hull = sys::bulletrsShapeHull_new(shape);
+        sys::bulletrsShapeHull_buildHull(hull, margin);
+        let points = read_points(
+            sys::bulletrsShapeHull_getVertexPointer(hull),
+            sys::bulletrsShapeHull_numVertices(hull),
+        );
+        sys::bulletrsShapeHull_delete(hull);
+        points
+    }
+}
+
+pub(crate) fn hull_computer_points(
+    points: &[BulletVector3],
+    shrink: f64,
+    shrink_clamp: f64,
+) -> Vec<BulletVector3> {
+    unsafe {
+        let computer = sys::bulletrsConvexHullComputer_new();
+        sys::bulletrsConvexHullComputer_compute(
+            computer,
+            points.as_ptr() as *const _,
+            ::std::mem::size_of::<BulletVector3>() as i32,
+            points.len() as i32,
+            shrink,
+            shrink_clamp,
+        );
+        let points = read_points(
+            sys::bulletrsConvexHullComputer_getVertices(computer),
+            sys::bulletrsConvexHullComputer_getNumVertices(computer),
+        );
+        sys::bulletrsConvexHullComputer_delete(computer);
+        points
+    }
+}
+
+unsafe fn read_points(points: *const sys::btVector3, count: i32) -> Vec<BulletVector3> {
+    (0..count as isize)
+        .map(|index| BulletVector3((*points.offset(index)).m_floats))
+        .collect()
+}
@@ -0,0 +1,40 @@
+use sys;
+use mint::Vector3;
+use super::triangle_mesh::MeshData;
+
+/// Concave triangle mesh usable with dynamic bodies.
+/// Owns vertex and index buffers, bullet only keeps pointers to them.
+pub struct GImpactMesh {
+    shape: *mut sys::btGImpactMeshShape,
+    mesh: MeshData,
+}
+
+impl GImpactMesh {
+    /// Every 3 indices make one triangle.
+    pub fn new<T: Into<Vector3<f64>> + Clone>(vertices: &[T], indices: &[u32]) -> GImpactMesh {
+        let mesh = MeshData::new(vertices, indices);
+        let shape = unsafe { sys::bulletrsGImpactMeshShape_new(mesh.as_ptr()) };
+
+        GImpactMesh { shape, mesh }
+    }
+
+    pub fn vertices(&self) -> &[f64] {
+        self.mesh.vertices()
+    }
+
+    pub fn indices(&self) -> &[i32] {
+        self.mesh.indices()
+    }
+
+    pub(crate) fn as_ptr(&self) -> *mut sys::btGImpactMeshShape {
+        self.shape
+    }
+}
+
+impl Drop for GImpactMesh {
+    fn drop(&mut self) {
+        unsafe {
+            sys::bulletrsGImpactMeshShape_delete(self.shape);
+        }
+    }
+}
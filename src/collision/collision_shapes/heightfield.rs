@@ -0,0 +1,90 @@
+use sys;
+use std::cell::Cell;
+
+/// Heightfield terrain, grid of `width * length` height samples.
+/// Bullet reads heights straight from the buffer owned by this struct,
+/// so heights may be updated in place while the shape is used by bodies.
+pub struct Heightfield {
+    shape: *mut sys::btHeightfieldTerrainShape,
+    heights: Box<[Cell<f64>]>,
+    width: usize,
+    length: usize,
+}
+
+impl Heightfield {
+    pub fn new<T: Into<f64> + Copy>(
+        width: usize,
+        length: usize,
+        heights: &[T],
+        min_height: f64,
+        max_height: f64,
+        up_axis: i32,
+        flip_quad_edges: bool,
+    ) -> Heightfield {
+        assert!(
+            heights.len() == width * length,
+            "Heights count should be equal to width * length"
+        );
+
+        let heights = heights
+            .iter()
+            .map(|height| Cell::new((*height).into()))
+            .collect::<Vec<_>>()
+            .into_boxed_slice();
+
+        let shape = unsafe {
+            sys::bulletrsHeightfieldTerrainShape_new(
+                width as i32,
+                length as i32,
+                heights.as_ptr() as *const _,
+                min_height,
+                max_height,
+                up_axis,
+                flip_quad_edges,
+            )
+        };
+
+        Heightfield {
+            shape,
+            heights,
+            width,
+            length,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    pub fn height(&self, x: usize, y: usize) -> f64 {
+        self.heights[self.index(x, y)].get()
+    }
+
+    /// Update one height sample.
+    /// New height should stay between min_height and max_height given on creation,
+    /// terrain's AABB will not be recalculated.
+    pub fn set_height(&self, x: usize, y: usize, height: f64) {
+        self.heights[self.index(x, y)].set(height);
+    }
+
+    fn index(&self, x: usize, y: usize) -> usize {
+        assert!(x < self.width && y < self.length, "Height sample out of bounds");
+        y * self.width + x
+    }
+
+    pub(crate) fn as_ptr(&self) -> *mut sys::btHeightfieldTerrainShape {
+        self.shape
+    }
+}
+
+impl Drop for Heightfield {
+    fn drop(&mut self) {
+        unsafe {
+            sys::bulletrsHeightfieldTerrainShape_delete(self.shape);
+        }
+    }
+}
@@ -1,17 +1,61 @@
+mod triangle_mesh;
+mod heightfield;
+mod gimpact;
+mod point_cloud;
+mod shared_shape;
+mod convex_hull;
+mod convex_decomposition;
+
+pub use self::triangle_mesh::TriangleMesh;
+pub use self::heightfield::Heightfield;
+pub use self::gimpact::GImpactMesh;
+pub use self::point_cloud::{ConvexPointCloud, PointBuffer};
+pub use self::shared_shape::SharedShape;
+pub use self::convex_hull::{ConvexHullOptions, ConvexPolyhedron, HullOptimization};
+pub use self::convex_decomposition::ConvexDecompositionParams;
+
 use sys;
-use std::mem;
+use std::rc::Rc;
 use bullet_vector3::BulletVector3;
+use errors::Error;
 use mint::{Vector3, Vector4};
 
+type CompoundMut<'a> = (
+    *mut sys::btCompoundShape,
+    &'a mut Vec<SharedShape>,
+    &'a mut Option<([f64; 4], f64)>,
+);
+
 pub enum Shape {
     Sphere(sys::btSphereShape),
     Plane(sys::btStaticPlaneShape),
     Box(sys::btBoxShape),
     Capsule(CapsuleShape),
+    Cylinder(CylinderShape),
+    Cone(ConeShape),
     ConvexHull(sys::btConvexHullShape),
     Compound {
         shape: sys::btCompoundShape,
-        child_shapes: Vec<Box<Shape>>,
+        child_shapes: Vec<SharedShape>,
+        /// Center of mass and principal axes in body space,
+        /// identity if masses of children are unknown.
+        principal: sys::btTransform,
+        /// Principal moments of inertia and total mass of children,
+        /// if masses of children are known.
+        principal_inertia: Option<([f64; 4], f64)>,
+    },
+    TriangleMesh(Rc<TriangleMesh>),
+    ScaledTriangleMesh {
+        shape: sys::btScaledBvhTriangleMeshShape,
+        mesh: Rc<TriangleMesh>,
+    },
+    Heightfield(Rc<Heightfield>),
+    GImpactMesh(GImpactMesh),
+    MultiSphere(sys::btMultiSphereShape),
+    ConvexPointCloud(ConvexPointCloud),
+    UniformScaling {
+        shape: sys::btUniformScalingShape,
+        child_shape: Box<Shape>,
     },
 }
 
@@ -28,8 +72,111 @@ impl CapsuleShape {
             &CapsuleShape::CapsuleZ(ref shape) => shape as *const _ as *mut _,
         }
     }
+
+    fn as_mut_ptr(&mut self) -> *mut sys::btCollisionShape {
+        match self {
+            &mut CapsuleShape::CapsuleX(ref mut shape) => shape as *mut _ as *mut _,
+            &mut CapsuleShape::CapsuleY(ref mut shape) => shape as *mut _ as *mut _,
+            &mut CapsuleShape::CapsuleZ(ref mut shape) => shape as *mut _ as *mut _,
+        }
+    }
+}
+
+pub enum CylinderShape {
+    CylinderX(sys::btCylinderShapeX),
+    CylinderY(sys::btCylinderShape),
+    CylinderZ(sys::btCylinderShapeZ),
+}
+impl CylinderShape {
+    pub(crate) fn as_ptr(&self) -> *mut sys::btCollisionShape {
+        match self {
+            &CylinderShape::CylinderX(ref shape) => shape as *const _ as *mut _,
+            &CylinderShape::CylinderY(ref shape) => shape as *const _ as *mut _,
+            &CylinderShape::CylinderZ(ref shape) => shape as *const _ as *mut _,
+        }
+    }
+
+    fn as_mut_ptr(&mut self) -> *mut sys::btCollisionShape {
+        match self {
+            &mut CylinderShape::CylinderX(ref mut shape) => shape as *mut _ as *mut _,
+            &mut CylinderShape::CylinderY(ref mut shape) => shape as *mut _ as *mut _,
+            &mut CylinderShape::CylinderZ(ref mut shape) => shape as *mut _ as *mut _,
+        }
+    }
+}
+
+pub enum ConeShape {
+    ConeX(sys::btConeShapeX),
+    ConeY(sys::btConeShape),
+    ConeZ(sys::btConeShapeZ),
+}
+impl ConeShape {
+    pub(crate) fn as_ptr(&self) -> *mut sys::btCollisionShape {
+        match self {
+            &ConeShape::ConeX(ref shape) => shape as *const _ as *mut _,
+            &ConeShape::ConeY(ref shape) => shape as *const _ as *mut _,
+            &ConeShape::ConeZ(ref shape) => shape as *const _ as *mut _,
+        }
+    }
+
+    fn as_mut_ptr(&mut self) -> *mut sys::btCollisionShape {
+        match self {
+            &mut ConeShape::ConeX(ref mut shape) => shape as *mut _ as *mut _,
+            &mut ConeShape::ConeY(ref mut shape) => shape as *mut _ as *mut _,
+            &mut ConeShape::ConeZ(ref mut shape) => shape as *mut _ as *mut _,
+        }
+    }
+}
+
+/// Bullet's type of collision shape.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ShapeType {
+    Box,
+    ConvexPointCloud,
+    ConvexHull,
+    Sphere,
+    MultiSphere,
+    Capsule,
+    Cone,
+    Cylinder,
+    UniformScaling,
+    TriangleMesh,
+    ScaledTriangleMesh,
+    Terrain,
+    GImpact,
+    StaticPlane,
+    Compound,
+    Other(u32),
 }
 
+impl From<u32> for ShapeType {
+    fn from(shape_type: u32) -> ShapeType {
+        match shape_type {
+            sys::BroadphaseNativeTypes_BOX_SHAPE_PROXYTYPE => ShapeType::Box,
+            sys::BroadphaseNativeTypes_CONVEX_POINT_CLOUD_SHAPE_PROXYTYPE => {
+                ShapeType::ConvexPointCloud
+            }
+            sys::BroadphaseNativeTypes_CONVEX_HULL_SHAPE_PROXYTYPE => ShapeType::ConvexHull,
+            sys::BroadphaseNativeTypes_SPHERE_SHAPE_PROXYTYPE => ShapeType::Sphere,
+            sys::BroadphaseNativeTypes_MULTI_SPHERE_SHAPE_PROXYTYPE => ShapeType::MultiSphere,
+            sys::BroadphaseNativeTypes_CAPSULE_SHAPE_PROXYTYPE => ShapeType::Capsule,
+            sys::BroadphaseNativeTypes_CONE_SHAPE_PROXYTYPE => ShapeType::Cone,
+            sys::BroadphaseNativeTypes_CYLINDER_SHAPE_PROXYTYPE => ShapeType::Cylinder,
+            sys::BroadphaseNativeTypes_UNIFORM_SCALING_SHAPE_PROXYTYPE => ShapeType::UniformScaling,
+            sys::BroadphaseNativeTypes_TRIANGLE_MESH_SHAPE_PROXYTYPE => ShapeType::TriangleMesh,
+            sys::BroadphaseNativeTypes_SCALED_TRIANGLE_MESH_SHAPE_PROXYTYPE => {
+                ShapeType::ScaledTriangleMesh
+            }
+            sys::BroadphaseNativeTypes_TERRAIN_SHAPE_PROXYTYPE => ShapeType::Terrain,
+            sys::BroadphaseNativeTypes_GIMPACT_SHAPE_PROXYTYPE => ShapeType::GImpact,
+            sys::BroadphaseNativeTypes_STATIC_PLANE_PROXYTYPE => ShapeType::StaticPlane,
+            sys::BroadphaseNativeTypes_COMPOUND_SHAPE_PROXYTYPE => ShapeType::Compound,
+            _ => ShapeType::Other(shape_type),
+        }
+    }
+}
+
+/// Main axis of capsules, cylinders and cones.
 pub enum CapsuleAxis {
     X,
     Y,
@@ -68,98 +215,684 @@ impl Shape {
         }
     }
 
+    /// Cylinder around the given axis.
+    /// Half extent along the axis is the half height, other two are radii.
+    pub fn new_cylinder<T: Into<Vector3<f64>>>(half_extents: T, axis: CapsuleAxis) -> Shape {
+        let half_extents: BulletVector3 = half_extents.into().into();
+        let half_extents = half_extents.0.as_ptr() as *const _;
+        match axis {
+            CapsuleAxis::X => Shape::Cylinder(CylinderShape::CylinderX(
+                unsafe { sys::btCylinderShapeX::new(half_extents) },
+            )),
+            CapsuleAxis::Y => Shape::Cylinder(CylinderShape::CylinderY(
+                unsafe { sys::btCylinderShape::new(half_extents) },
+            )),
+            CapsuleAxis::Z => Shape::Cylinder(CylinderShape::CylinderZ(
+                unsafe { sys::btCylinderShapeZ::new(half_extents) },
+            )),
+        }
+    }
+
+    /// Cone centered around the origin with its tip pointing along the given axis.
+    pub fn new_cone(radius: f64, height: f64, axis: CapsuleAxis) -> Shape {
+        match axis {
+            CapsuleAxis::X => Shape::Cone(ConeShape::ConeX(
+                unsafe { sys::btConeShapeX::new(radius, height) },
+            )),
+            CapsuleAxis::Y => Shape::Cone(ConeShape::ConeY(
+                unsafe { sys::btConeShape::new(radius, height) },
+            )),
+            CapsuleAxis::Z => Shape::Cone(ConeShape::ConeZ(
+                unsafe { sys::btConeShapeZ::new(radius, height) },
+            )),
+        }
+    }
+
     pub fn new_convex_hull<T: Into<Vector3<f64>> + Clone>(vertices: &[T]) -> Shape {
-        let mut shape = unsafe { sys::btConvexHullShape::new(::std::ptr::null(), 0, 8 * 4) };
-        for vertex in vertices.iter() {
-            let vertex: BulletVector3 = vertex.clone().into().into();
+        let points = vertices
+            .iter()
+            .map(|vertex| vertex.clone().into().into())
+            .collect::<Vec<BulletVector3>>();
+        Shape::ConvexHull(Shape::convex_hull_from_points(&points))
+    }
+
+    /// Convex hull with its point set optimized before use.
+    /// The final points may be read back with Shape::convex_hull_points.
+    pub fn new_convex_hull_with_options<T: Into<Vector3<f64>> + Clone>(
+        vertices: &[T],
+        options: ConvexHullOptions,
+    ) -> Shape {
+        let points = vertices
+            .iter()
+            .map(|vertex| vertex.clone().into().into())
+            .collect::<Vec<BulletVector3>>();
+
+        let mut shape = match options.optimization {
+            HullOptimization::None => Shape::convex_hull_from_points(&points),
+            HullOptimization::ShapeHull => {
+                let shape = Shape::convex_hull_from_points(&points);
+                let margin = unsafe {
+                    sys::bulletrsCollisionShape_getMargin(&shape as *const _ as *mut _)
+                };
+                let points = convex_hull::shape_hull_points(&shape as *const _ as *const _, margin);
+                Shape::convex_hull_from_points(&points)
+            }
+            HullOptimization::HullComputer {
+                shrink,
+                shrink_clamp,
+            } => {
+                let points = convex_hull::hull_computer_points(&points, shrink, shrink_clamp);
+                Shape::convex_hull_from_points(&points)
+            }
+        };
+
+        if options.polyhedral_features {
             unsafe {
-                shape.addPoint(&vertex as *const _ as *const _, true);
+                sys::btPolyhedralConvexShape_initializePolyhedralFeatures(
+                    &mut shape as *mut _ as *mut _,
+                    0,
+                );
             }
         }
         Shape::ConvexHull(shape)
     }
 
-    pub fn new_compound<T, T1>(shapes: Vec<(Shape, T, T1)>) -> Shape
+    fn convex_hull_from_points(points: &[BulletVector3]) -> sys::btConvexHullShape {
+        let mut shape = unsafe { sys::btConvexHullShape::new(::std::ptr::null(), 0, 8 * 4) };
+        for point in points.iter() {
+            unsafe {
+                shape.addPoint(point as *const _ as *const _, true);
+            }
+        }
+        shape
+    }
+
+    /// Compound of convex hulls approximating a concave triangle mesh.
+    /// Every 3 indices make one triangle. Hulls are placed at compound's origin.
+    pub fn new_convex_decomposition<T: Into<Vector3<f64>> + Clone>(
+        vertices: &[T],
+        indices: &[u32],
+        params: ConvexDecompositionParams,
+    ) -> Shape {
+        let hulls = convex_decomposition::decompose(vertices, indices, &params);
+        Shape::new_compound(
+            hulls
+                .iter()
+                .map(|points| {
+                    (
+                        Shape::ConvexHull(Shape::convex_hull_from_points(points)),
+                        Vector3 {
+                            x: 0.0,
+                            y: 0.0,
+                            z: 0.0,
+                        },
+                        Vector4 {
+                            x: 0.0,
+                            y: 0.0,
+                            z: 0.0,
+                            w: 1.0,
+                        },
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    /// Child shapes may be either owned `Shape`s or `SharedShape`s used elsewhere.
+    pub fn new_compound<S, T, T1>(shapes: Vec<(S, T, T1)>) -> Shape
     where
+        S: Into<SharedShape>,
         T: Into<Vector3<f64>>,
         T1: Into<Vector4<f64>>,
     {
-        let mut child_shapes = vec![];
-        let mut compound_shape = unsafe { sys::btCompoundShape::new(true, 0) };
+        let mut compound = Shape::Compound {
+            shape: unsafe { sys::btCompoundShape::new(true, 0) },
+            child_shapes: vec![],
+            principal: unsafe { *sys::btTransform::getIdentity() },
+            principal_inertia: None,
+        };
         for (shape, position, orientation) in shapes.into_iter() {
-            let shape_box = Box::new(shape);
-            let orientation: [f64; 4] = orientation.into().into();
-            let position: BulletVector3 = position.into().into();
-            let transform = unsafe {
-                sys::btTransform::new1(
-                    &orientation as *const _ as *const _,
-                    &position as *const _ as *const _,
-                )
-            };
+            compound.add_child(shape, position, orientation).unwrap();
+        }
+        compound
+    }
+
+    /// Compound with its origin moved to the center of mass and axes aligned with
+    /// principal axes of inertia, calculated from masses of children.
+    /// Returns the shape and its new origin in the space children were given in.
+    /// RigidBody::new applies this offset, positions of bodies with this shape
+    /// are read and written in the original space.
+    /// Inertia of the shape is the principal inertia scaled to body's mass,
+    /// until its children are changed.
+    pub fn new_compound_with_masses<S, T, T1>(
+        shapes: Vec<(S, f64, T, T1)>,
+    ) -> (Shape, (Vector3<f64>, Vector4<f64>))
+    where
+        S: Into<SharedShape>,
+        T: Into<Vector3<f64>>,
+        T1: Into<Vector4<f64>>,
+    {
+        let mut masses = vec![];
+        let mut compound = Shape::new_compound(
+            shapes
+                .into_iter()
+                .map(|(shape, mass, position, orientation)| {
+                    masses.push(mass);
+                    (shape, position, orientation)
+                })
+                .collect(),
+        );
+
+        if let &mut Shape::Compound {
+            ref mut shape,
+            ref mut principal,
+            ref mut principal_inertia,
+            ..
+        } = &mut compound
+        {
+            let mut inertia: [f64; 4] = [0.0; 4];
             unsafe {
-                compound_shape.addChildShape(&transform as *const _, shape_box.as_ptr());
+                sys::btCompoundShape_calculatePrincipalAxisTransform(
+                    shape,
+                    masses.as_mut_ptr(),
+                    principal as *mut _,
+                    inertia.as_mut_ptr() as *mut _,
+                );
+                let inverse = principal.inverse();
+                for index in 0..masses.len() as i32 {
+                    let mut transform = sys::btTransform::new();
+                    transform.mult(
+                        &inverse as *const _,
+                        sys::btCompoundShape_getChildTransform1(shape, index),
+                    );
+                    sys::btCompoundShape_updateChildTransform(
+                        shape,
+                        index,
+                        &transform as *const _,
+                        false,
+                    );
+                }
+                sys::btCompoundShape_recalculateLocalAabb(shape as *mut _ as *mut _);
             }
-            child_shapes.push(shape_box);
+            *principal_inertia = Some((inertia, masses.iter().sum()));
+        }
+
+        let offset = position_and_orientation(compound.principal_transform().unwrap());
+        (compound, offset)
+    }
+
+    /// Center of mass offset of compounds created with Shape::new_compound_with_masses.
+    pub(crate) fn principal_transform(&self) -> Option<&sys::btTransform> {
+        match self {
+            &Shape::Compound { ref principal, .. } => Some(principal),
+            _ => None,
+        }
+    }
+
+    /// Add child shape to compound, compound's AABB is recalculated.
+    /// Returns Error::WrongShapeType if the shape is not a compound.
+    /// Bodies keep their inertia, change shapes of bodies with
+    /// RigidBodyHandle::update_shape to recalculate it.
+    pub fn add_child<S, T, T1>(
+        &mut self,
+        shape: S,
+        position: T,
+        orientation: T1,
+    ) -> Result<(), Error>
+    where
+        S: Into<SharedShape>,
+        T: Into<Vector3<f64>>,
+        T1: Into<Vector4<f64>>,
+    {
+        let (compound, child_shapes, principal_inertia) = self.compound_mut()?;
+        let shape: SharedShape = shape.into();
+        let transform = Shape::transform_from(position, orientation);
+        unsafe {
+            sys::btCompoundShape_addChildShape(compound, &transform as *const _, shape.as_ptr());
+        }
+        child_shapes.push(shape);
+        *principal_inertia = None;
+        Ok(())
+    }
+
+    /// Remove child shape from compound, compound's AABB is recalculated.
+    /// The last child takes place of the removed one.
+    /// Returns Error::NoValue if there is no child with this index.
+    pub fn remove_child(&mut self, index: usize) -> Result<SharedShape, Error> {
+        let (compound, child_shapes, principal_inertia) = self.compound_mut()?;
+        if index >= child_shapes.len() {
+            return Err(Error::NoValue);
         }
-        Shape::Compound {
-            shape: compound_shape,
-            child_shapes,
+        unsafe {
+            sys::btCompoundShape_removeChildShapeByIndex(compound, index as i32);
+            sys::btCompoundShape_recalculateLocalAabb(compound as *mut _);
+        }
+        *principal_inertia = None;
+        Ok(child_shapes.swap_remove(index))
+    }
+
+    /// Move child shape, compound's AABB is recalculated.
+    /// Returns Error::NoValue if there is no child with this index.
+    pub fn update_child_transform<T, T1>(
+        &mut self,
+        index: usize,
+        position: T,
+        orientation: T1,
+    ) -> Result<(), Error>
+    where
+        T: Into<Vector3<f64>>,
+        T1: Into<Vector4<f64>>,
+    {
+        let (compound, child_shapes, principal_inertia) = self.compound_mut()?;
+        if index >= child_shapes.len() {
+            return Err(Error::NoValue);
+        }
+        let transform = Shape::transform_from(position, orientation);
+        unsafe {
+            sys::btCompoundShape_updateChildTransform(
+                compound,
+                index as i32,
+                &transform as *const _,
+                true,
+            );
+        }
+        *principal_inertia = None;
+        Ok(())
+    }
+
+    /// Number of children of compound, 0 for other shapes.
+    pub fn child_count(&self) -> usize {
+        match self {
+            &Shape::Compound {
+                ref child_shapes, ..
+            } => child_shapes.len(),
+            _ => 0,
+        }
+    }
+
+    pub fn child_shape(&self, index: usize) -> Option<SharedShape> {
+        match self {
+            &Shape::Compound {
+                ref child_shapes, ..
+            } => child_shapes.get(index).cloned(),
+            _ => None,
+        }
+    }
+
+    /// Position and orientation of child shape in compound's space.
+    pub fn child_transform(&self, index: usize) -> Option<(Vector3<f64>, Vector4<f64>)> {
+        match self {
+            &Shape::Compound { ref shape, .. } if index < self.child_count() => {
+                Some(position_and_orientation(unsafe {
+                    &*sys::btCompoundShape_getChildTransform1(shape, index as i32)
+                }))
+            }
+            _ => None,
         }
     }
 
+    /// Compound's shape, children and inertia from masses of children.
+    /// The inertia should be cleared once children are changed, as it doesn't match them.
+    fn compound_mut<'a>(&'a mut self) -> Result<CompoundMut<'a>, Error> {
+        match self {
+            &mut Shape::Compound {
+                ref mut shape,
+                ref mut child_shapes,
+                ref mut principal_inertia,
+                ..
+            } => Ok((shape as *mut _, child_shapes, principal_inertia)),
+            _ => Err(Error::WrongShapeType),
+        }
+    }
+
+    fn transform_from<T, T1>(position: T, orientation: T1) -> sys::btTransform
+    where
+        T: Into<Vector3<f64>>,
+        T1: Into<Vector4<f64>>,
+    {
+        let orientation: [f64; 4] = orientation.into().into();
+        let position: BulletVector3 = position.into().into();
+        unsafe {
+            sys::btTransform::new1(
+                &orientation as *const _ as *const _,
+                &position as *const _ as *const _,
+            )
+        }
+    }
+
+    /// Convex hull of spheres with given centers and radii.
+    pub fn new_multi_sphere<T: Into<Vector3<f64>> + Clone>(spheres: &[(T, f64)]) -> Shape {
+        let positions = spheres
+            .iter()
+            .map(|&(ref position, _)| position.clone().into().into())
+            .collect::<Vec<BulletVector3>>();
+        let radii = spheres.iter().map(|&(_, radius)| radius).collect::<Vec<f64>>();
+
+        Shape::MultiSphere(unsafe {
+            sys::btMultiSphereShape::new(
+                positions.as_ptr() as *const _,
+                radii.as_ptr(),
+                spheres.len() as i32,
+            )
+        })
+    }
+
+    /// Convex hull of points, referencing given buffer without copying.
+    /// Same buffer may be shared by many point clouds.
+    pub fn new_convex_point_cloud<T: Into<Vector3<f64>>>(
+        points: Rc<PointBuffer>,
+        scaling: T,
+    ) -> Shape {
+        Shape::ConvexPointCloud(ConvexPointCloud::new(points, scaling))
+    }
+
+    /// Convex shape scaled by the same factor along all axes.
+    /// Panics if `shape` is not convex.
+    pub fn new_uniform_scaling(shape: Shape, scaling_factor: f64) -> Shape {
+        assert!(shape.is_convex(), "Only convex shapes may be uniformly scaled");
+
+        let child_shape = Box::new(shape);
+        let shape = unsafe {
+            sys::btUniformScalingShape::new(child_shape.as_ptr() as *mut _, scaling_factor)
+        };
+        Shape::UniformScaling { shape, child_shape }
+    }
+
+    /// Static concave mesh, every 3 indices make one triangle.
+    /// Should be used only with zero mass bodies.
+    pub fn new_triangle_mesh<T>(vertices: &[T], indices: &[u32]) -> Shape
+    where
+        T: Into<Vector3<f64>> + Clone,
+    {
+        Shape::TriangleMesh(Rc::new(TriangleMesh::new(vertices, indices)))
+    }
+
+    /// Scaled instance of already created triangle mesh.
+    /// Mesh data and BVH are shared with the original shape.
+    /// Panics if `mesh` is not a triangle mesh shape.
+    pub fn new_scaled_triangle_mesh<T: Into<Vector3<f64>>>(mesh: &Shape, scaling: T) -> Shape {
+        let mesh = match mesh {
+            &Shape::TriangleMesh(ref mesh) => mesh.clone(),
+            &Shape::ScaledTriangleMesh { ref mesh, .. } => mesh.clone(),
+            _ => panic!("Scaled triangle mesh may be created only from triangle mesh shape"),
+        };
+        let scaling: BulletVector3 = scaling.into().into();
+        let shape = unsafe {
+            sys::btScaledBvhTriangleMeshShape::new(mesh.as_ptr(), scaling.0.as_ptr() as *const _)
+        };
+        Shape::ScaledTriangleMesh { shape, mesh }
+    }
+
+    /// Static heightfield terrain of `width * length` samples, row by row.
+    /// Terrain is centered around the origin, so in up axis it is placed
+    /// between -(max_height - min_height) / 2 and (max_height - min_height) / 2.
+    pub fn new_heightfield<T: Into<f64> + Copy>(
+        width: usize,
+        length: usize,
+        heights: &[T],
+        min_height: f64,
+        max_height: f64,
+        up_axis: CapsuleAxis,
+        flip_quad_edges: bool,
+    ) -> Shape {
+        let up_axis = match up_axis {
+            CapsuleAxis::X => 0,
+            CapsuleAxis::Y => 1,
+            CapsuleAxis::Z => 2,
+        };
+        Shape::Heightfield(Rc::new(Heightfield::new(
+            width,
+            length,
+            heights,
+            min_height,
+            max_height,
+            up_axis,
+            flip_quad_edges,
+        )))
+    }
+
+    /// Concave mesh for dynamic bodies, every 3 indices make one triangle.
+    /// Much slower than convex shapes, consider compound of convex hulls first.
+    pub fn new_gimpact_mesh<T>(vertices: &[T], indices: &[u32]) -> Shape
+    where
+        T: Into<Vector3<f64>> + Clone,
+    {
+        Shape::GImpactMesh(GImpactMesh::new(vertices, indices))
+    }
+
+    /// Heightfield data of heightfield shape.
+    /// Clone it before giving shape to rigid body to update heights later.
+    pub fn heightfield(&self) -> Option<&Rc<Heightfield>> {
+        match self {
+            &Shape::Heightfield(ref heightfield) => Some(heightfield),
+            _ => None,
+        }
+    }
+
+    /// Convex shapes may be wrapped by `new_uniform_scaling`.
+    pub fn is_convex(&self) -> bool {
+        match self {
+            &Shape::Sphere(_)
+            | &Shape::Box(_)
+            | &Shape::Capsule(_)
+            | &Shape::Cylinder(_)
+            | &Shape::Cone(_)
+            | &Shape::ConvexHull(_)
+            | &Shape::MultiSphere(_)
+            | &Shape::ConvexPointCloud(_)
+            | &Shape::UniformScaling { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// Compute faces and edges of boxes, convex hulls and convex point clouds.
+    /// Returns false for other shapes or if bullet failed to build the polyhedron.
+    pub fn initialize_polyhedral_features(&mut self) -> bool {
+        match self.shape_type() {
+            ShapeType::Box | ShapeType::ConvexHull | ShapeType::ConvexPointCloud => unsafe {
+                sys::btPolyhedralConvexShape_initializePolyhedralFeatures(
+                    self.as_mut_ptr() as *mut _,
+                    0,
+                )
+            },
+            _ => false,
+        }
+    }
+
+    /// Faces and edges computed by initialize_polyhedral_features.
+    /// None if features were not initialized for this shape.
+    pub fn convex_polyhedron(&self) -> Option<ConvexPolyhedron> {
+        match self {
+            &Shape::Box(_) | &Shape::ConvexHull(_) | &Shape::ConvexPointCloud(_) => unsafe {
+                let polyhedron = sys::btPolyhedralConvexShape_getConvexPolyhedron(
+                    self.as_ptr() as *const _,
+                );
+                if polyhedron.is_null() {
+                    None
+                } else {
+                    Some(ConvexPolyhedron::from_ptr(polyhedron))
+                }
+            },
+            _ => None,
+        }
+    }
+
+    /// Unscaled points of a convex hull, after optimization.
+    pub fn convex_hull_points(&self) -> Option<Vec<Vector3<f64>>> {
+        match self {
+            &Shape::ConvexHull(ref shape) => unsafe {
+                let points = sys::btConvexHullShape_getUnscaledPoints1(shape);
+                let count = sys::btConvexHullShape_getNumPoints(shape);
+                Some(
+                    (0..count as isize)
+                        .map(|index| {
+                            let point = &*points.offset(index);
+                            ::bullet_vector3::vector_from_slice(&point.m_floats[0..3])
+                        })
+                        .collect(),
+                )
+            },
+            _ => None,
+        }
+    }
+
+    pub fn shape_type(&self) -> ShapeType {
+        let shape_type = unsafe { sys::btCollisionShape_getShapeType(self.as_ptr()) };
+        ShapeType::from(shape_type as u32)
+    }
+
+    /// Scale shape along its local axes.
+    /// Triangle mesh and heightfield data is shared by every shape made from it,
+    /// scaling such shape affects all of them.
+    /// Shapes of bodies are scaled with RigidBodyHandle::update_shape.
+    pub fn set_local_scaling<T: Into<Vector3<f64>>>(&mut self, scaling: T) {
+        let scaling: BulletVector3 = scaling.into().into();
+        unsafe {
+            sys::bulletrsCollisionShape_setLocalScaling(
+                self.as_mut_ptr(),
+                scaling.0.as_ptr() as *const _,
+            );
+        }
+    }
+
+    pub fn local_scaling(&self) -> Vector3<f64> {
+        let scaling = unsafe { &*sys::bulletrsCollisionShape_getLocalScaling(self.as_ptr()) };
+        ::bullet_vector3::vector_from_slice(&scaling.m_floats[0..3])
+    }
+
+    /// Set collision margin, the distance at which contacts are detected.
+    pub fn set_margin(&mut self, margin: f64) {
+        unsafe { sys::bulletrsCollisionShape_setMargin(self.as_mut_ptr(), margin) }
+    }
+
+    pub fn margin(&self) -> f64 {
+        unsafe { sys::bulletrsCollisionShape_getMargin(self.as_ptr()) }
+    }
+
+    /// Axis aligned bounding box of the shape placed with given position and orientation.
+    /// Returns (min, max) corners.
+    pub fn aabb<T, T1>(&self, position: T, orientation: T1) -> (Vector3<f64>, Vector3<f64>)
+    where
+        T: Into<Vector3<f64>>,
+        T1: Into<Vector4<f64>>,
+    {
+        let transform = Shape::transform_from(position, orientation);
+        let mut min: [f64; 4] = [0.0; 4];
+        let mut max: [f64; 4] = [0.0; 4];
+        unsafe {
+            sys::bulletrsCollisionShape_getAabb(
+                self.as_ptr(),
+                &transform as *const _,
+                min.as_mut_ptr() as *mut _,
+                max.as_mut_ptr() as *mut _,
+            );
+        }
+        (
+            ::bullet_vector3::vector_from_slice(&min[0..3]),
+            ::bullet_vector3::vector_from_slice(&max[0..3]),
+        )
+    }
+
+    /// Triangulate the shape placed with given position and orientation.
+    /// Curved convex shapes are approximated by their hull, planes by a big quad.
+    /// Every 3 indices make one triangle.
+    pub fn to_triangle_mesh<T, T1>(
+        &self,
+        position: T,
+        orientation: T1,
+    ) -> (Vec<Vector3<f64>>, Vec<u32>)
+    where
+        T: Into<Vector3<f64>>,
+        T1: Into<Vector4<f64>>,
+    {
+        let transform = Shape::transform_from(position, orientation);
+        unsafe {
+            let mesh = sys::bulletrsTriangleMeshExport_new(self.as_ptr(), &transform as *const _);
+            let vertices = (0..sys::bulletrsTriangleMeshExport_getNumVertices(mesh))
+                .map(|index| {
+                    let vertex = &*sys::bulletrsTriangleMeshExport_getVertex(mesh, index);
+                    ::bullet_vector3::vector_from_slice(&vertex.m_floats[0..3])
+                })
+                .collect();
+            let indices = (0..sys::bulletrsTriangleMeshExport_getNumIndices(mesh))
+                .map(|index| sys::bulletrsTriangleMeshExport_getIndex(mesh, index) as u32)
+                .collect();
+            sys::bulletrsTriangleMeshExport_delete(mesh);
+            (vertices, indices)
+        }
+    }
+
+    /// Sphere containing the whole shape, in shape's local space.
+    /// Returns (center, radius).
+    pub fn bounding_sphere(&self) -> (Vector3<f64>, f64) {
+        let mut center: [f64; 4] = [0.0; 4];
+        let mut radius = 0.0;
+        unsafe {
+            sys::btCollisionShape_getBoundingSphere(
+                self.as_ptr() as *mut _,
+                center.as_mut_ptr() as *mut _,
+                &mut radius,
+            );
+        }
+        (::bullet_vector3::vector_from_slice(&center[0..3]), radius)
+    }
+
     pub(crate) fn as_ptr(&self) -> *mut sys::btCollisionShape {
         match self {
             &Shape::Sphere(ref shape) => shape as *const _ as *mut _,
             &Shape::Plane(ref shape) => shape as *const _ as *mut _,
             &Shape::Box(ref shape) => shape as *const _ as *mut _,
             &Shape::Capsule(ref shape) => shape.as_ptr(),
+            &Shape::Cylinder(ref shape) => shape.as_ptr(),
+            &Shape::Cone(ref shape) => shape.as_ptr(),
             &Shape::ConvexHull(ref shape) => shape as *const _ as *mut _,
             &Shape::Compound { ref shape, .. } => shape as *const _ as *mut _,
+            &Shape::TriangleMesh(ref mesh) => mesh.as_ptr() as *mut _,
+            &Shape::ScaledTriangleMesh { ref shape, .. } => shape as *const _ as *mut _,
+            &Shape::Heightfield(ref heightfield) => heightfield.as_ptr() as *mut _,
+            &Shape::GImpactMesh(ref mesh) => mesh.as_ptr() as *mut _,
+            &Shape::MultiSphere(ref shape) => shape as *const _ as *mut _,
+            &Shape::ConvexPointCloud(ref shape) => shape.as_ptr() as *mut _,
+            &Shape::UniformScaling { ref shape, .. } => shape as *const _ as *mut _,
         }
     }
 
-    pub fn calculate_local_inertia(&self, mass: f64) -> Vector3<f64> {
-        let mut inertia: [f64; 4] = unsafe { mem::uninitialized() };
+    /// Pointer for changing the shape, shapes behind Rc or raw pointers are shared
+    /// by design and changed for every user.
+    fn as_mut_ptr(&mut self) -> *mut sys::btCollisionShape {
         match self {
-            &Shape::Sphere(ref sphere) => unsafe {
-                sys::btSphereShape_calculateLocalInertia(
-                    sphere as *const _ as *mut _,
-                    mass,
-                    inertia.as_mut_ptr() as *mut _,
-                );
-            },
-            &Shape::Plane(ref sphere) => unsafe {
-                sys::btStaticPlaneShape_calculateLocalInertia(
-                    sphere as *const _ as *mut _,
-                    mass,
-                    inertia.as_mut_ptr() as *mut _,
-                );
-            },
-            &Shape::Box(ref shape) => unsafe {
-                sys::btBoxShape_calculateLocalInertia(
-                    shape as *const _ as *mut _,
-                    mass,
-                    inertia.as_mut_ptr() as *mut _,
-                );
-            },
-            &Shape::Capsule(ref shape) => unsafe {
-                sys::btCapsuleShape_calculateLocalInertia(
-                    shape.as_ptr() as *mut _,
-                    mass,
-                    inertia.as_mut_ptr() as *mut _,
-                );
-            },
+            &mut Shape::Sphere(ref mut shape) => shape as *mut _ as *mut _,
+            &mut Shape::Plane(ref mut shape) => shape as *mut _ as *mut _,
+            &mut Shape::Box(ref mut shape) => shape as *mut _ as *mut _,
+            &mut Shape::Capsule(ref mut shape) => shape.as_mut_ptr(),
+            &mut Shape::Cylinder(ref mut shape) => shape.as_mut_ptr(),
+            &mut Shape::Cone(ref mut shape) => shape.as_mut_ptr(),
+            &mut Shape::ConvexHull(ref mut shape) => shape as *mut _ as *mut _,
+            &mut Shape::Compound { ref mut shape, .. } => shape as *mut _ as *mut _,
+            &mut Shape::TriangleMesh(ref mesh) => mesh.as_ptr() as *mut _,
+            &mut Shape::ScaledTriangleMesh { ref mut shape, .. } => shape as *mut _ as *mut _,
+            &mut Shape::Heightfield(ref heightfield) => heightfield.as_ptr() as *mut _,
+            &mut Shape::GImpactMesh(ref mesh) => mesh.as_ptr() as *mut _,
+            &mut Shape::MultiSphere(ref mut shape) => shape as *mut _ as *mut _,
+            &mut Shape::ConvexPointCloud(ref shape) => shape.as_ptr() as *mut _,
+            &mut Shape::UniformScaling { ref mut shape, .. } => shape as *mut _ as *mut _,
+        }
+    }
 
-            &Shape::ConvexHull(ref shape) => unsafe {
-                sys::btPolyhedralConvexShape_calculateLocalInertia(
-                    shape as *const _ as *mut _,
-                    mass,
-                    inertia.as_mut_ptr() as *mut _,
-                );
-            },
-            &Shape::Compound { ref shape, .. } => unsafe {
-                sys::btCompoundShape_calculateLocalInertia(
-                    shape as *const _ as *mut _,
+    pub fn calculate_local_inertia(&self, mass: f64) -> Vector3<f64> {
+        let mut inertia: [f64; 4] = [0.0; 4];
+        match self {
+            &Shape::Compound {
+                principal_inertia: Some((ref principal_inertia, total_mass)),
+                ..
+            } => {
+                for (value, principal) in inertia.iter_mut().zip(principal_inertia.iter()) {
+                    *value = principal * mass / total_mass;
+                }
+            }
+            _ => unsafe {
+                sys::bulletrsCollisionShape_calculateLocalInertia(
+                    self.as_ptr(),
                     mass,
                     inertia.as_mut_ptr() as *mut _,
                 );
@@ -168,3 +901,12 @@ impl Shape {
         ::bullet_vector3::vector_from_slice(&inertia[0..3])
     }
 }
+
+fn position_and_orientation(transform: &sys::btTransform) -> (Vector3<f64>, Vector4<f64>) {
+    let origin = unsafe { transform.getOrigin1().as_ref().unwrap() };
+    let rotation = unsafe { transform.getRotation() };
+    (
+        ::bullet_vector3::vector_from_slice(&origin.m_floats[0..3]),
+        ::bullet_vector3::vector4_from_slice(&rotation._base.m_floats),
+    )
+}
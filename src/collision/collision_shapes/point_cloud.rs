@@ -0,0 +1,73 @@
+use sys;
+use bullet_vector3::BulletVector3;
+use mint::Vector3;
+use std::rc::Rc;
+
+/// Points copied once into bullet's vector layout.
+/// Many convex point clouds may share the same buffer without copying it.
+///
+/// The copy can't be avoided: btConvexPointCloudShape keeps a pointer to an array of btVector3,
+/// which are four 16 byte aligned btScalar, while user points are three f64 of any layout.
+pub struct PointBuffer {
+    points: Vec<BulletVector3>,
+}
+
+impl PointBuffer {
+    pub fn new<T: Into<Vector3<f64>> + Clone>(points: &[T]) -> PointBuffer {
+        PointBuffer {
+            points: points.iter().map(|point| point.clone().into().into()).collect(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn points(&self) -> Vec<Vector3<f64>> {
+        self.points
+            .iter()
+            .map(|point| ::bullet_vector3::vector_from_slice(&point.0[0..3]))
+            .collect()
+    }
+}
+
+/// Convex shape made of points from shared buffer.
+/// The points are not copied again, the shape keeps the buffer alive.
+pub struct ConvexPointCloud {
+    shape: *mut sys::btConvexPointCloudShape,
+    points: Rc<PointBuffer>,
+}
+
+impl ConvexPointCloud {
+    pub fn new<T: Into<Vector3<f64>>>(points: Rc<PointBuffer>, scaling: T) -> ConvexPointCloud {
+        let scaling: BulletVector3 = scaling.into().into();
+        let shape = unsafe {
+            sys::bulletrsConvexPointCloudShape_new(
+                points.points.as_ptr() as *mut _,
+                points.len() as i32,
+                scaling.0.as_ptr() as *const _,
+            )
+        };
+        ConvexPointCloud { shape, points }
+    }
+
+    pub fn points(&self) -> &Rc<PointBuffer> {
+        &self.points
+    }
+
+    pub(crate) fn as_ptr(&self) -> *mut sys::btConvexPointCloudShape {
+        self.shape
+    }
+}
+
+impl Drop for ConvexPointCloud {
+    fn drop(&mut self) {
+        unsafe {
+            sys::bulletrsConvexPointCloudShape_delete(self.shape);
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use sys;
+use std::ops::Deref;
+use std::rc::Rc;
+use super::Shape;
+
+/// Reference counted shape, may be used by any number of rigid bodies and compound shapes.
+/// Bullet shape is destroyed only after the last user is dropped.
+///
+/// Uses Rc rather than Arc: Shape holds raw bullet pointers and bullet shapes and bodies are not
+/// thread safe, so Arc would not make it Send or Sync. Shared shapes, like the worlds using them,
+/// can't be moved or shared across threads.
+#[derive(Clone)]
+pub struct SharedShape(Rc<Shape>);
+
+impl SharedShape {
+    pub fn new(shape: Shape) -> SharedShape {
+        SharedShape(Rc::new(shape))
+    }
+
+    /// Number of bodies, compound shapes and SharedShape clones using this shape.
+    pub fn strong_count(this: &SharedShape) -> usize {
+        Rc::strong_count(&this.0)
+    }
+
+    /// Mutable access to the shape, None if it is used anywhere else.
+    pub fn get_mut(this: &mut SharedShape) -> Option<&mut Shape> {
+        Rc::get_mut(&mut this.0)
+    }
+
+    pub(crate) fn as_ptr(&self) -> *mut sys::btCollisionShape {
+        self.0.as_ptr()
+    }
+}
+
+impl Deref for SharedShape {
+    type Target = Shape;
+
+    fn deref(&self) -> &Shape {
+        &self.0
+    }
+}
+
+impl From<Shape> for SharedShape {
+    fn from(shape: Shape) -> SharedShape {
+        SharedShape::new(shape)
+    }
+}
@@ -0,0 +1,104 @@
+use sys;
+use bullet_vector3::BulletVector3;
+use mint::Vector3;
+use std::cell::UnsafeCell;
+
+/// Vertex and index buffers with bullet's mesh interface pointing into them.
+pub(crate) struct MeshData {
+    mesh_interface: Box<sys::btTriangleIndexVertexArray>,
+    vertices: Vec<f64>,
+    indices: Vec<i32>,
+}
+
+impl MeshData {
+    /// Every 3 indices make one triangle.
+    pub fn new<T: Into<Vector3<f64>> + Clone>(vertices: &[T], indices: &[u32]) -> MeshData {
+        assert!(indices.len() % 3 == 0, "Indices count should be multiple of 3");
+
+        let mut vertices = vertices
+            .iter()
+            .flat_map(|vertex| {
+                let vertex: BulletVector3 = vertex.clone().into().into();
+                vertex.0[0..3].to_vec()
+            })
+            .collect::<Vec<f64>>();
+        let mut indices = indices.iter().map(|index| *index as i32).collect::<Vec<i32>>();
+
+        let mesh_interface = Box::new(unsafe {
+            sys::btTriangleIndexVertexArray::new1(
+                indices.len() as i32 / 3,
+                indices.as_mut_ptr(),
+                3 * ::std::mem::size_of::<i32>() as i32,
+                vertices.len() as i32 / 3,
+                vertices.as_mut_ptr(),
+                3 * ::std::mem::size_of::<f64>() as i32,
+            )
+        });
+
+        MeshData {
+            mesh_interface,
+            vertices,
+            indices,
+        }
+    }
+
+    pub fn vertices(&self) -> &[f64] {
+        &self.vertices
+    }
+
+    pub fn indices(&self) -> &[i32] {
+        &self.indices
+    }
+
+    pub fn as_ptr(&self) -> *mut sys::btStridingMeshInterface {
+        &*self.mesh_interface as *const _ as *mut _
+    }
+}
+
+impl Drop for MeshData {
+    fn drop(&mut self) {
+        unsafe {
+            sys::bulletrsTriangleIndexVertexArray_destruct(&mut *self.mesh_interface as *mut _);
+        }
+    }
+}
+
+/// Static triangle mesh with quantized BVH.
+/// Owns vertex and index buffers, bullet only keeps pointers to them.
+pub struct TriangleMesh {
+    /// Shared by every shape made from the mesh, changed through shared references
+    /// when the mesh is scaled.
+    shape: UnsafeCell<sys::btBvhTriangleMeshShape>,
+    mesh: MeshData,
+}
+
+impl TriangleMesh {
+    /// Every 3 indices make one triangle.
+    pub fn new<T: Into<Vector3<f64>> + Clone>(vertices: &[T], indices: &[u32]) -> TriangleMesh {
+        let mesh = MeshData::new(vertices, indices);
+        let shape = unsafe { sys::btBvhTriangleMeshShape::new(mesh.as_ptr(), true, true) };
+        let shape = UnsafeCell::new(shape);
+
+        TriangleMesh { shape, mesh }
+    }
+
+    pub fn vertices(&self) -> &[f64] {
+        self.mesh.vertices()
+    }
+
+    pub fn indices(&self) -> &[i32] {
+        self.mesh.indices()
+    }
+
+    pub(crate) fn as_ptr(&self) -> *mut sys::btBvhTriangleMeshShape {
+        self.shape.get()
+    }
+}
+
+impl Drop for TriangleMesh {
+    fn drop(&mut self) {
+        unsafe {
+            sys::bulletrsBvhTriangleMeshShape_destruct(self.shape.get());
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use sys;
+use dynamics::rigid_body::RigidBody;
+use errors::Error;
+use std::cell::RefMut;
+use std::collections::HashMap;
+
+struct BodySlot {
+    generation: u32,
+    body: Option<RigidBody>,
+}
+
+/// Rigid bodies of the world, addressed by index and generation.
+/// Generation of a slot changes when its body is removed,
+/// so handles to the removed body become stale when the slot is reused.
+pub struct BodyStorage {
+    slots: Vec<BodySlot>,
+    free_slots: Vec<usize>,
+    /// Slot index by address of body's collision object.
+    indices: HashMap<usize, usize>,
+}
+
+impl BodyStorage {
+    pub(crate) fn new() -> BodyStorage {
+        BodyStorage {
+            slots: vec![],
+            free_slots: vec![],
+            indices: HashMap::new(),
+        }
+    }
+
+    /// Returns index and generation of the body.
+    pub(crate) fn insert(&mut self, body: RigidBody) -> (usize, u32) {
+        let index = match self.free_slots.pop() {
+            Some(index) => index,
+            None => {
+                self.slots.push(BodySlot {
+                    generation: 0,
+                    body: None,
+                });
+                self.slots.len() - 1
+            }
+        };
+        self.indices.insert(unsafe { body.as_ptr() } as usize, index);
+        let slot = &mut self.slots[index];
+        slot.body = Some(body);
+        (index, slot.generation)
+    }
+
+    pub(crate) fn remove(&mut self, index: usize, generation: u32) -> Result<RigidBody, Error> {
+        self.get(index, generation)?;
+        let slot = &mut self.slots[index];
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free_slots.push(index);
+
+        let body = slot.body.take().unwrap();
+        self.indices.remove(&(unsafe { body.as_ptr() } as usize));
+        Ok(body)
+    }
+
+    pub(crate) fn get(&self, index: usize, generation: u32) -> Result<&RigidBody, Error> {
+        match self.slots.get(index) {
+            Some(&BodySlot {
+                generation: slot_generation,
+                body: Some(ref body),
+            }) if slot_generation == generation =>
+            {
+                Ok(body)
+            }
+            _ => Err(Error::BodyDeleted),
+        }
+    }
+
+    pub(crate) fn get_mut(
+        &mut self,
+        index: usize,
+        generation: u32,
+    ) -> Result<&mut RigidBody, Error> {
+        match self.slots.get_mut(index) {
+            Some(&mut BodySlot {
+                generation: slot_generation,
+                body: Some(ref mut body),
+            }) if slot_generation == generation =>
+            {
+                Ok(body)
+            }
+            _ => Err(Error::BodyDeleted),
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Index and generation of the body owning given collision object.
+    /// The pointer is only compared, never dereferenced.
+    pub(crate) fn find(
+        &self,
+        collision_object: *const sys::btCollisionObject,
+    ) -> Option<(usize, u32)> {
+        self.indices
+            .get(&(collision_object as usize))
+            .map(|&index| (index, self.slots[index].generation))
+    }
+
+    /// Index and generation of every body.
+    pub(crate) fn keys(&self) -> Vec<(usize, u32)> {
+        self.slots
+            .iter()
+            .enumerate()
+            .filter(|&(_, slot)| slot.body.is_some())
+            .map(|(index, slot)| (index, slot.generation))
+            .collect()
+    }
+}
+
+/// Mutable access to all bodies of the world, one body at a time.
+/// Bodies stay borrowed until BodiesMut is dropped, accessing them through handles
+/// meanwhile returns Error::BodiesBorrowed.
+pub struct BodiesMut<'a> {
+    bodies: RefMut<'a, BodyStorage>,
+    index: usize,
+}
+
+impl<'a> BodiesMut<'a> {
+    pub(crate) fn new(bodies: RefMut<'a, BodyStorage>) -> BodiesMut<'a> {
+        BodiesMut { bodies, index: 0 }
+    }
+
+    /// Next body of the world, the reference is valid until the following call.
+    pub fn next_body(&mut self) -> Option<&mut RigidBody> {
+        let index = self.bodies.slots[self.index..]
+            .iter()
+            .position(|slot| slot.body.is_some())
+            .map(|position| self.index + position)?;
+        self.index = index + 1;
+        self.bodies.slots[index].body.as_mut()
+    }
+}
@@ -1,9 +1,13 @@
 use sys;
-use {BulletVector3, RigidBodyHandle, Vector3};
+use {BulletVector3, Error, RigidBodyHandle, Vector3};
 use super::TypedConstraint;
 
+/// Hinge between two bodies.
+/// Keeps handles of the bodies, the constraint can be added to the world only
+/// while both of them are still there.
 pub struct HingeConstraint {
     constraint: Box<sys::btHingeConstraint>,
+    bodies: [RigidBodyHandle; 2],
 }
 
 impl HingeConstraint {
@@ -20,17 +24,21 @@ impl HingeConstraint {
         axis_in_a: T2,
         axis_in_b: T3,
         use_reference_frame_a: bool,
-    ) -> HingeConstraint {
+    ) -> Result<HingeConstraint, Error> {
         let pivot_in_a: BulletVector3 = pivot_in_a.into().into();
         let pivot_in_b: BulletVector3 = pivot_in_b.into().into();
         let axis_in_a: BulletVector3 = axis_in_a.into().into();
         let axis_in_b: BulletVector3 = axis_in_b.into().into();
 
-        HingeConstraint {
+        let bodies = [rb_a.clone(), rb_b.clone()];
+        let rb_a = rb_a.body()?;
+        let rb_b = rb_b.body()?;
+        Ok(HingeConstraint {
+            bodies,
             constraint: unsafe {
                 Box::new(sys::btHingeConstraint::new(
-                    rb_a.ptr,
-                    rb_b.ptr,
+                    rb_a,
+                    rb_b,
                     pivot_in_a.0.as_ptr() as *const _,
                     pivot_in_b.0.as_ptr() as *const _,
                     axis_in_a.0.as_ptr() as *const _,
@@ -38,7 +46,7 @@ impl HingeConstraint {
                     use_reference_frame_a,
                 ))
             },
-        }
+        })
     }
 
     pub fn new1(
@@ -47,18 +55,22 @@ impl HingeConstraint {
         rb_a_frame: &sys::btTransform,
         rb_b_frame: &sys::btTransform,
         use_reference_frame_a: bool,
-    ) -> HingeConstraint {
-        HingeConstraint {
+    ) -> Result<HingeConstraint, Error> {
+        let bodies = [rb_a.clone(), rb_b.clone()];
+        let rb_a = rb_a.body()?;
+        let rb_b = rb_b.body()?;
+        Ok(HingeConstraint {
+            bodies,
             constraint: unsafe {
                 Box::new(sys::btHingeConstraint::new2(
-                    rb_a.ptr,
-                    rb_b.ptr,
+                    rb_a,
+                    rb_b,
                     rb_a_frame as *const _,
                     rb_b_frame as *const _,
                     use_reference_frame_a,
                 ))
             },
-        }
+        })
     }
 }
 
@@ -66,4 +78,8 @@ impl TypedConstraint for HingeConstraint {
     fn as_ptr(&self) -> *mut sys::btTypedConstraint {
         &*self.constraint as *const _ as *mut _
     }
+
+    fn bodies(&self) -> &[RigidBodyHandle] {
+        &self.bodies
+    }
 }
@@ -1,5 +1,9 @@
 use sys;
+use RigidBodyHandle;
 
 pub trait TypedConstraint {
     fn as_ptr(&self) -> *mut sys::btTypedConstraint;
+
+    /// Bodies the constraint is attached to, checked when it is added to a world.
+    fn bodies(&self) -> &[RigidBodyHandle];
 }
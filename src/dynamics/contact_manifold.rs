@@ -0,0 +1,43 @@
+use sys;
+use dynamics::rigid_body::RigidBodyHandle;
+use mint::Vector3;
+
+/// Contact point between two bodies, in world space.
+#[derive(Debug, Clone)]
+pub struct ContactPoint {
+    pub position_on_a: Vector3<f64>,
+    pub position_on_b: Vector3<f64>,
+    /// Contact normal on body B, pointing towards body A.
+    pub normal: Vector3<f64>,
+    /// Distance between the bodies along the normal, negative when they penetrate.
+    pub distance: f64,
+    /// Impulse applied by the solver during the last simulation step.
+    pub applied_impulse: f64,
+    pub combined_friction: f64,
+    pub combined_restitution: f64,
+}
+
+impl ContactPoint {
+    pub(crate) fn from_manifold_point(point: &sys::btManifoldPoint) -> ContactPoint {
+        ContactPoint {
+            position_on_a: ::bullet_vector3::vector_from_slice(
+                &point.m_positionWorldOnA.m_floats[0..3],
+            ),
+            position_on_b: ::bullet_vector3::vector_from_slice(
+                &point.m_positionWorldOnB.m_floats[0..3],
+            ),
+            normal: ::bullet_vector3::vector_from_slice(&point.m_normalWorldOnB.m_floats[0..3]),
+            distance: point.m_distance1,
+            applied_impulse: point.m_appliedImpulse,
+            combined_friction: point.m_combinedFriction,
+            combined_restitution: point.m_combinedRestitution,
+        }
+    }
+}
+
+/// Contact points of two touching bodies.
+pub struct ContactManifold {
+    pub body_a: RigidBodyHandle,
+    pub body_b: RigidBodyHandle,
+    pub points: Vec<ContactPoint>,
+}
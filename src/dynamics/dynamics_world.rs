@@ -1,22 +1,30 @@
 use sys;
 
-use collision::broadphase_collision::Broadphase;
+use collision::broadphase_collision::{Broadphase, CollisionGroup};
 use collision::collision_dispatch::{CollisionConfiguration, CollisionDispatcher};
 use dynamics::constraint_solver::{ConstraintSolver, TypedConstraint};
+use dynamics::contact_manifold::{ContactManifold, ContactPoint};
+use dynamics::overlap_filter::OverlapFilter;
 use dynamics::rigid_body::{RigidBody, RigidBodyHandle};
+use dynamics::body_storage::{BodiesMut, BodyStorage};
 use bullet_vector3::BulletVector3;
+use errors::Error;
 use mint::Vector3;
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
 
 /// Owner of all rigidbodys of the world
 struct InternalWorldData {
-    rigid_bodys: Vec<RigidBody>,
+    rigid_bodys: Rc<RefCell<BodyStorage>>,
     constraints: Vec<Box<TypedConstraint>>,
+    overlap_filter: Option<OverlapFilter>,
 }
 impl InternalWorldData {
     pub fn new() -> Self {
         InternalWorldData {
-            rigid_bodys: vec![],
+            rigid_bodys: Rc::new(RefCell::new(BodyStorage::new())),
             constraints: vec![],
+            overlap_filter: None,
         }
     }
 }
@@ -49,6 +57,12 @@ impl DynamicsWorld {
         let broadphase_box = Box::new(broadphase);
         let solver_box = Box::new(solver);
         let configuration_box = Box::new(configuration);
+        unsafe {
+            // GImpact meshes collide with everything only with registered algorithm
+            sys::bulletrsGImpactCollisionAlgorithm_registerAlgorithm(
+                dispatcher_box.as_ptr() as *mut _,
+            );
+        }
         DynamicsWorld {
             implementation: WorldImplementation::Discrete {
                 world: unsafe {
@@ -83,37 +97,202 @@ impl DynamicsWorld {
     }
 
     pub fn add_rigid_body(&mut self, rigid_body: RigidBody) -> RigidBodyHandle {
-        self.world_data.rigid_bodys.push(rigid_body);
-        let added_element = self.world_data.rigid_bodys.last().unwrap();
+        let ptr = unsafe { rigid_body.as_ptr() };
+        let (index, generation) = self.world_data.rigid_bodys.borrow_mut().insert(rigid_body);
         match &self.implementation {
             &WorldImplementation::Discrete { ref world, .. } => unsafe {
-                sys::btDiscreteDynamicsWorld_addRigidBody(
+                sys::btDiscreteDynamicsWorld_addRigidBody(world as *const _ as *mut _, ptr);
+            },
+        }
+        RigidBodyHandle::new(self.weak_bodies(), index, generation)
+    }
+
+    /// Add body colliding only with bodies which groups are in its mask
+    /// and which masks contain its group.
+    /// Bodies added with add_rigid_body are in DEFAULT group with ALL mask,
+    /// or in STATIC group with mask of everything except STATIC for static bodies.
+    pub fn add_rigid_body_with_filter(
+        &mut self,
+        rigid_body: RigidBody,
+        group: CollisionGroup,
+        mask: CollisionGroup,
+    ) -> RigidBodyHandle {
+        let ptr = unsafe { rigid_body.as_ptr() };
+        let (index, generation) = self.world_data.rigid_bodys.borrow_mut().insert(rigid_body);
+        match &self.implementation {
+            &WorldImplementation::Discrete { ref world, .. } => unsafe {
+                sys::btDiscreteDynamicsWorld_addRigidBody1(
                     world as *const _ as *mut _,
-                    added_element.as_ptr(),
+                    ptr,
+                    group.bits(),
+                    mask.bits(),
                 );
-                RigidBodyHandle::new(added_element.as_ptr(), added_element.motion_state_ptr())
             },
         }
+        RigidBodyHandle::new(self.weak_bodies(), index, generation)
     }
 
-    pub fn add_rigid_body_handle(&mut self, rigid_body: &RigidBodyHandle) {
+    /// Change collision filter of a body in the world.
+    /// Broadphase proxy of the body is recreated, so its pairs are found again
+    /// with the new filter even if the body doesn't move. The body is woken up.
+    pub fn set_collision_filter(
+        &mut self,
+        rigid_body: &RigidBodyHandle,
+        group: CollisionGroup,
+        mask: CollisionGroup,
+    ) -> Result<(), Error> {
+        let body = rigid_body.body()?;
+        let proxy = rigid_body.broadphase_proxy()?;
         match &self.implementation {
-            &WorldImplementation::Discrete { ref world, .. } => unsafe {
-                sys::btDiscreteDynamicsWorld_addRigidBody(
+            &WorldImplementation::Discrete { ref world, ref init_data, .. } => unsafe {
+                init_data.1.clean_proxy_from_pairs(proxy, init_data.0.as_ptr());
+                sys::bulletrsCollisionWorld_setCollisionFilter(
                     world as *const _ as *mut _,
-                    rigid_body.ptr,
+                    body as *mut _,
+                    group.bits(),
+                    mask.bits(),
                 );
+                sys::btCollisionObject_activate(body as *mut _, false);
+            },
+        }
+        Ok(())
+    }
+
+    /// Remove body from the world and give it back, with its user data.
+    /// Constraints attached to the body are removed and dropped.
+    /// Handles to the body return Error::BodyDeleted afterwards,
+    /// the body may be added again to get a new handle.
+    pub fn remove_body(&mut self, rigid_body: &RigidBodyHandle) -> Result<RigidBody, Error> {
+        let body = self.world_data
+            .rigid_bodys
+            .borrow_mut()
+            .remove(rigid_body.index(), rigid_body.generation())?;
+        let body_ptr = unsafe { body.as_ptr() };
+
+        let (attached, constraints) = self.world_data
+            .constraints
+            .drain(..)
+            .partition::<Vec<_>, _>(|constraint| unsafe {
+                let constraint = &*constraint.as_ptr();
+                constraint.m_rbA == body_ptr || constraint.m_rbB == body_ptr
+            });
+        self.world_data.constraints = constraints;
+
+        match &self.implementation {
+            &WorldImplementation::Discrete { ref world, .. } => unsafe {
+                for constraint in &attached {
+                    sys::btDiscreteDynamicsWorld_removeConstraint(
+                        world as *const _ as *mut _,
+                        constraint.as_ptr(),
+                    );
+                }
+                sys::btDiscreteDynamicsWorld_removeRigidBody(world as *const _ as *mut _, body_ptr);
             },
         }
+        Ok(body)
+    }
+
+    /// Handles of all bodies of the world.
+    pub fn bodies(&self) -> ::std::vec::IntoIter<RigidBodyHandle> {
+        let keys = self.world_data.rigid_bodys.borrow().keys();
+        keys.into_iter()
+            .map(|(index, generation)| RigidBodyHandle::new(self.weak_bodies(), index, generation))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    /// All bodies of the world, for changes available only through RigidBody,
+    /// like motion states and collision flags.
+    pub fn bodies_mut<'a>(&'a mut self) -> BodiesMut<'a> {
+        BodiesMut::new(self.world_data.rigid_bodys.borrow_mut())
+    }
+
+    pub fn body_count(&self) -> usize {
+        self.world_data.rigid_bodys.borrow().len()
+    }
+
+    /// First body with given user index.
+    pub fn body_by_user_index(&self, user_index: i32) -> Option<RigidBodyHandle> {
+        self.bodies().find(|body| body.get_user_index() == Ok(user_index))
+    }
+
+    /// Handle of the body owning bullet's collision object,
+    /// None if the object is not a body of this world.
+    /// The pointer is only compared with world's bodies and never dereferenced.
+    pub fn handle_from_collision_object(
+        &self,
+        collision_object: *const sys::btCollisionObject,
+    ) -> Option<RigidBodyHandle> {
+        let (index, generation) = self.world_data.rigid_bodys.borrow().find(collision_object)?;
+        Some(RigidBodyHandle::new(self.weak_bodies(), index, generation))
+    }
+
+    fn weak_bodies(&self) -> Weak<RefCell<BodyStorage>> {
+        Rc::downgrade(&self.world_data.rigid_bodys)
+    }
+
+    /// Bodies touching after the last simulation step, with their contact points.
+    /// Pairs of bodies which bounding boxes overlap without contact are skipped.
+    pub fn contact_manifolds(&self) -> ::std::vec::IntoIter<ContactManifold> {
+        let dispatcher = match &self.implementation {
+            &WorldImplementation::Discrete { ref init_data, .. } => init_data.0.as_ptr(),
+        };
+        let bodies = self.world_data.rigid_bodys.borrow();
+        let mut manifolds = vec![];
+
+        let manifolds_count =
+            unsafe { sys::btCollisionDispatcher_getNumManifolds(dispatcher as *mut _) };
+        for i in 0..manifolds_count {
+            let manifold = unsafe {
+                &*sys::btCollisionDispatcher_getManifoldByIndexInternal(dispatcher as *mut _, i)
+            };
+            if manifold.m_cachedPoints == 0 {
+                continue;
+            }
+            let found = (bodies.find(manifold.m_body0), bodies.find(manifold.m_body1));
+            let (body_a, body_b) = match found {
+                (Some(body_a), Some(body_b)) => (body_a, body_b),
+                _ => continue,
+            };
+
+            manifolds.push(ContactManifold {
+                body_a: RigidBodyHandle::new(self.weak_bodies(), body_a.0, body_a.1),
+                body_b: RigidBodyHandle::new(self.weak_bodies(), body_b.0, body_b.1),
+                points: manifold.m_pointCache[0..manifold.m_cachedPoints as usize]
+                    .iter()
+                    .map(ContactPoint::from_manifold_point)
+                    .collect(),
+            });
+        }
+        manifolds.into_iter()
+    }
+
+    /// Decide in a closure whether bodies passing group and mask filtering may collide.
+    /// It is called during simulation step when AABBs of two bodies start to overlap,
+    /// bodies already overlapping are not checked again until they separate.
+    /// Panic in the closure aborts the process, it can not unwind through bullet.
+    pub fn set_overlap_filter<F>(&mut self, filter: F)
+    where
+        F: FnMut(&RigidBodyHandle, &RigidBodyHandle) -> bool + 'static,
+    {
+        let filter = OverlapFilter::new(self.weak_bodies(), filter);
+        self.set_overlap_filter_callback(filter.as_ptr());
+        self.world_data.overlap_filter = Some(filter);
+    }
+
+    /// Restore bullet's default filtering by groups and masks.
+    pub fn clear_overlap_filter(&mut self) {
+        self.set_overlap_filter_callback(::std::ptr::null_mut());
+        self.world_data.overlap_filter = None;
     }
 
-    pub fn remove_body(&mut self, rigid_body: &RigidBodyHandle) {
+    fn set_overlap_filter_callback(&self, callback: *mut sys::btOverlapFilterCallback) {
         match &self.implementation {
             &WorldImplementation::Discrete { ref world, .. } => unsafe {
-                sys::btDiscreteDynamicsWorld_removeRigidBody(
+                sys::bulletrsCollisionWorld_setOverlapFilterCallback(
                     world as *const _ as *mut _,
-                    rigid_body.ptr,
-                )
+                    callback,
+                );
             },
         }
     }
@@ -150,6 +329,7 @@ impl DynamicsWorld {
     {
         let from = callback.world_from();
         let to = callback.world_to();
+        callback.set_bodies(self.weak_bodies());
         match &self.implementation {
             &WorldImplementation::Discrete { ref world, .. } => unsafe {
                 sys::btCollisionWorld_rayTest(
@@ -163,11 +343,23 @@ impl DynamicsWorld {
         callback
     }
 
+    /// Add constraint between bodies of this world.
+    /// Returns Error::BodyDeleted if one of its bodies was removed since the constraint
+    /// was created, and Error::NoValue if it belongs to another world.
     pub fn add_constraint<T: TypedConstraint + 'static>(
         &mut self,
         constraint: T,
         disable_collision_between_linked_bodies: bool,
-    ) {
+    ) -> Result<(), Error> {
+        {
+            let bodies = self.world_data.rigid_bodys.borrow();
+            for handle in constraint.bodies() {
+                let body = handle.body()?;
+                if bodies.find(body as *const _) != Some((handle.index(), handle.generation())) {
+                    return Err(Error::NoValue);
+                }
+            }
+        }
         let constraint_box = Box::new(constraint);
         self.world_data.constraints.push(constraint_box);
         let added_element = self.world_data.constraints.last().unwrap();
@@ -180,6 +372,7 @@ impl DynamicsWorld {
                 )
             },
         }
+        Ok(())
     }
 }
 
@@ -187,20 +380,23 @@ pub struct RayIntersection {
     pub fraction: f64,
     pub normal: Vector3<f64>,
     pub point: Vector3<f64>,
+    /// Part of concave shape that was hit, -1 for convex shapes.
+    /// For heightfields this is the column of the hit quad.
+    pub shape_part: i32,
+    /// Index of hit triangle for concave shapes, -1 for convex shapes.
+    /// For heightfields this is the row of the hit quad.
+    pub triangle_index: i32,
 
     collision_object: *const sys::btCollisionObject,
+    bodies: Weak<RefCell<BodyStorage>>,
 }
 
 impl RayIntersection {
+    /// None if nothing was hit or the world was dropped.
     pub fn rigidbody(&self) -> Option<RigidBodyHandle> {
-        if self.collision_object.is_null() {
-            return None;
-        } else {
-            let rigid_body = self.collision_object as *mut sys::btRigidBody;
-            let motion_state =
-                unsafe { &(*rigid_body).m_optionalMotionState as *const _ as *mut _ };
-            Some(RigidBodyHandle::new(rigid_body, motion_state))
-        }
+        let bodies = self.bodies.upgrade()?;
+        let (index, generation) = bodies.borrow().find(self.collision_object)?;
+        Some(RigidBodyHandle::new(self.bodies.clone(), index, generation))
     }
 }
 /// Internal and unsafe methods.
@@ -209,6 +405,7 @@ pub trait InternalRayResultCallback {
     fn world_from(&self) -> sys::btVector3;
     fn world_to(&self) -> sys::btVector3;
     fn as_ptr(&mut self) -> *mut sys::btCollisionWorld_RayResultCallback;
+    fn set_bodies(&mut self, bodies: Weak<RefCell<BodyStorage>>);
 }
 
 pub trait RayResultCallback {
@@ -216,7 +413,8 @@ pub trait RayResultCallback {
 }
 
 pub struct AllRayResultCallback {
-    callback: sys::btCollisionWorld_AllHitsRayResultCallback,
+    callback: sys::bulletrsAllHitsRayResultCallback,
+    bodies: Weak<RefCell<BodyStorage>>,
 }
 
 impl AllRayResultCallback {
@@ -230,52 +428,85 @@ impl AllRayResultCallback {
 
         AllRayResultCallback {
             callback: unsafe {
-                sys::btCollisionWorld_AllHitsRayResultCallback::new(
+                sys::bulletrsAllHitsRayResultCallback::new(
                     &from.0 as *const _ as *const _,
                     &to.0 as *const _ as *const _,
                 )
             },
+            bodies: Weak::new(),
+        }
+    }
+
+    /// Hit only bodies which groups are in the mask and which masks contain the group.
+    /// By default ray is in DEFAULT group and hits everything.
+    pub fn set_collision_filter(&mut self, group: CollisionGroup, mask: CollisionGroup) {
+        self.callback._base._base.m_collisionFilterGroup = group.bits();
+        self.callback._base._base.m_collisionFilterMask = mask.bits();
+    }
+}
+
+impl Drop for AllRayResultCallback {
+    fn drop(&mut self) {
+        unsafe {
+            sys::bulletrsAllHitsRayResultCallback_destruct(&mut self.callback as *mut _);
         }
     }
 }
 
 impl InternalRayResultCallback for AllRayResultCallback {
     fn world_from(&self) -> sys::btVector3 {
-        self.callback.m_rayFromWorld.clone()
+        self.callback._base.m_rayFromWorld.clone()
     }
     fn world_to(&self) -> sys::btVector3 {
-        self.callback.m_rayToWorld.clone()
+        self.callback._base.m_rayToWorld.clone()
     }
 
     fn as_ptr(&mut self) -> *mut sys::btCollisionWorld_RayResultCallback {
         &mut self.callback as *mut _ as *mut _
     }
+
+    fn set_bodies(&mut self, bodies: Weak<RefCell<BodyStorage>>) {
+        self.bodies = bodies;
+    }
 }
 
 impl RayResultCallback for AllRayResultCallback {
     fn intersections(&self) -> Vec<RayIntersection> {
         let normals = unsafe {
             ::std::slice::from_raw_parts(
-                self.callback.m_hitNormalWorld.m_data,
-                self.callback.m_hitNormalWorld.m_size as usize,
+                self.callback._base.m_hitNormalWorld.m_data,
+                self.callback._base.m_hitNormalWorld.m_size as usize,
             )
         };
         let points = unsafe {
             ::std::slice::from_raw_parts(
-                self.callback.m_hitPointWorld.m_data,
-                self.callback.m_hitPointWorld.m_size as usize,
+                self.callback._base.m_hitPointWorld.m_data,
+                self.callback._base.m_hitPointWorld.m_size as usize,
             )
         };
         let fractions = unsafe {
             ::std::slice::from_raw_parts(
-                self.callback.m_hitFractions.m_data,
-                self.callback.m_hitFractions.m_size as usize,
+                self.callback._base.m_hitFractions.m_data,
+                self.callback._base.m_hitFractions.m_size as usize,
             )
         };
         let objects = unsafe {
             ::std::slice::from_raw_parts(
-                self.callback.m_collisionObjects.m_data,
-                self.callback.m_collisionObjects.m_size as usize,
+                self.callback._base.m_collisionObjects.m_data,
+                self.callback._base.m_collisionObjects.m_size as usize,
+            )
+        };
+
+        let shape_parts = unsafe {
+            ::std::slice::from_raw_parts(
+                self.callback.m_shapeParts.m_data,
+                self.callback.m_shapeParts.m_size as usize,
+            )
+        };
+        let triangle_indices = unsafe {
+            ::std::slice::from_raw_parts(
+                self.callback.m_triangleIndices.m_data,
+                self.callback.m_triangleIndices.m_size as usize,
             )
         };
 
@@ -284,7 +515,10 @@ impl RayResultCallback for AllRayResultCallback {
         for i in 0..normals.len() {
             intersections.push(RayIntersection {
                 collision_object: objects[i],
+                bodies: self.bodies.clone(),
                 fraction: fractions[i],
+                shape_part: shape_parts[i],
+                triangle_index: triangle_indices[i],
                 normal: ::bullet_vector3::vector_from_slice(&normals[i].m_floats[0..3]),
                 point: ::bullet_vector3::vector_from_slice(&points[i].m_floats[0..3]),
             });
@@ -294,7 +528,8 @@ impl RayResultCallback for AllRayResultCallback {
 }
 
 pub struct ClosestRayResultCallback {
-    callback: sys::btCollisionWorld_ClosestRayResultCallback,
+    callback: sys::bulletrsClosestRayResultCallback,
+    bodies: Weak<RefCell<BodyStorage>>,
 }
 
 impl ClosestRayResultCallback {
@@ -308,43 +543,66 @@ impl ClosestRayResultCallback {
 
         ClosestRayResultCallback {
             callback: unsafe {
-                sys::btCollisionWorld_ClosestRayResultCallback::new(
+                sys::bulletrsClosestRayResultCallback::new(
                     &from.0 as *const _ as *const _,
                     &to.0 as *const _ as *const _,
                 )
             },
+            bodies: Weak::new(),
         }
     }
 
     pub fn closest_hit_fraction(&self) -> f64 {
-        self.callback._base.m_closestHitFraction
+        self.callback._base._base.m_closestHitFraction
+    }
+
+    /// Hit only bodies which groups are in the mask and which masks contain the group.
+    /// By default ray is in DEFAULT group and hits everything.
+    pub fn set_collision_filter(&mut self, group: CollisionGroup, mask: CollisionGroup) {
+        self.callback._base._base.m_collisionFilterGroup = group.bits();
+        self.callback._base._base.m_collisionFilterMask = mask.bits();
+    }
+}
+
+impl Drop for ClosestRayResultCallback {
+    fn drop(&mut self) {
+        unsafe {
+            sys::bulletrsClosestRayResultCallback_destruct(&mut self.callback as *mut _);
+        }
     }
 }
 
 impl InternalRayResultCallback for ClosestRayResultCallback {
     fn world_from(&self) -> sys::btVector3 {
-        self.callback.m_rayFromWorld.clone()
+        self.callback._base.m_rayFromWorld.clone()
     }
     fn world_to(&self) -> sys::btVector3 {
-        self.callback.m_rayToWorld.clone()
+        self.callback._base.m_rayToWorld.clone()
     }
     fn as_ptr(&mut self) -> *mut sys::btCollisionWorld_RayResultCallback {
         &mut self.callback as *mut _ as *mut _
     }
+
+    fn set_bodies(&mut self, bodies: Weak<RefCell<BodyStorage>>) {
+        self.bodies = bodies;
+    }
 }
 
 impl RayResultCallback for ClosestRayResultCallback {
     fn intersections(&self) -> Vec<RayIntersection> {
         vec![
             RayIntersection {
-                collision_object: self.callback._base.m_collisionObject,
-                fraction: self.callback._base.m_closestHitFraction,
+                collision_object: self.callback._base._base.m_collisionObject,
+                bodies: self.bodies.clone(),
+                fraction: self.callback._base._base.m_closestHitFraction,
                 point: ::bullet_vector3::vector_from_slice(
-                    &self.callback.m_hitPointWorld.m_floats[0..3],
+                    &self.callback._base.m_hitPointWorld.m_floats[0..3],
                 ),
                 normal: ::bullet_vector3::vector_from_slice(
-                    &self.callback.m_hitNormalWorld.m_floats[0..3],
+                    &self.callback._base.m_hitNormalWorld.m_floats[0..3],
                 ),
+                shape_part: self.callback.m_shapePart,
+                triangle_index: self.callback.m_triangleIndex,
             },
         ]
     }
@@ -1,3 +1,8 @@
+pub mod body_storage;
 pub mod constraint_solver;
+pub mod contact_manifold;
 pub mod dynamics_world;
+pub mod motion_state;
+pub mod overlap_filter;
 pub mod rigid_body;
+pub mod rigid_body_builder;
@@ -0,0 +1,63 @@
+use sys;
+use bullet_vector3::BulletVector3;
+use mint::{Vector3, Vector4};
+use std::os::raw::c_void;
+use std::panic::{self, AssertUnwindSafe};
+
+/// Link between a rigid body and its user-side representation, such as scene graph node.
+/// Transforms are position and orientation quaternion of the body in world space.
+pub trait MotionState {
+    /// Initial transform of the body.
+    /// For kinematic bodies it is asked every simulation step.
+    fn get_world_transform(&self) -> (Vector3<f64>, Vector4<f64>);
+
+    /// Called by bullet after simulation step only for active bodies,
+    /// with transform interpolated between fixed time steps.
+    fn set_world_transform(&mut self, position: Vector3<f64>, orientation: Vector4<f64>);
+}
+
+/// Double boxed, so bullet gets a thin pointer to the trait object.
+pub(crate) type MotionStateBox = Box<Box<MotionState>>;
+
+pub(crate) unsafe fn set_callbacks(
+    motion_state: *mut sys::bulletrsMotionState,
+    user_motion_state: &MotionStateBox,
+) {
+    sys::bulletrsMotionState_setCallbacks(
+        motion_state,
+        &**user_motion_state as *const _ as *mut c_void,
+        Some(get_world_transform),
+        Some(set_world_transform),
+    );
+}
+
+/// Panics can not unwind into bullet, abort instead.
+pub(crate) fn abort_on_panic<F: FnOnce() -> R, R>(f: F) -> R {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => result,
+        Err(_) => ::std::process::abort(),
+    }
+}
+
+unsafe extern "C" fn get_world_transform(user_data: *mut c_void, transform: *mut sys::btTransform) {
+    let motion_state = &*(user_data as *const Box<MotionState>);
+    let (position, orientation) = abort_on_panic(|| motion_state.get_world_transform());
+    let orientation: [f64; 4] = orientation.into();
+    let position: BulletVector3 = position.into();
+    *transform = sys::btTransform::new1(
+        &orientation as *const _ as *const _,
+        &position as *const _ as *const _,
+    );
+}
+
+unsafe extern "C" fn set_world_transform(
+    user_data: *mut c_void,
+    transform: *const sys::btTransform,
+) {
+    let motion_state = &mut *(user_data as *mut Box<MotionState>);
+    let origin = &*(*transform).getOrigin1();
+    let rotation = (*transform).getRotation();
+    let position = ::bullet_vector3::vector_from_slice(&origin.m_floats[0..3]);
+    let orientation = ::bullet_vector3::vector4_from_slice(&rotation._base.m_floats);
+    abort_on_panic(|| motion_state.set_world_transform(position, orientation));
+}
@@ -0,0 +1,76 @@
+use sys;
+use dynamics::body_storage::BodyStorage;
+use dynamics::motion_state::abort_on_panic;
+use dynamics::rigid_body::RigidBodyHandle;
+use std::cell::RefCell;
+use std::os::raw::c_void;
+use std::rc::Weak;
+
+struct FilterData {
+    bodies: Weak<RefCell<BodyStorage>>,
+    filter: Box<FnMut(&RigidBodyHandle, &RigidBodyHandle) -> bool>,
+}
+
+/// Rust closure installed as btOverlapFilterCallback of world's pair cache.
+pub(crate) struct OverlapFilter {
+    callback: *mut sys::bulletrsOverlapFilterCallback,
+    data: *mut FilterData,
+}
+
+impl OverlapFilter {
+    pub fn new<F>(bodies: Weak<RefCell<BodyStorage>>, filter: F) -> OverlapFilter
+    where
+        F: FnMut(&RigidBodyHandle, &RigidBodyHandle) -> bool + 'static,
+    {
+        let data = Box::into_raw(Box::new(FilterData {
+            bodies,
+            filter: Box::new(filter),
+        }));
+        let callback = unsafe {
+            sys::bulletrsOverlapFilterCallback_new(
+                data as *mut c_void,
+                Some(need_broadphase_collision),
+            )
+        };
+        OverlapFilter { callback, data }
+    }
+
+    pub fn as_ptr(&self) -> *mut sys::btOverlapFilterCallback {
+        self.callback as *mut _
+    }
+}
+
+impl Drop for OverlapFilter {
+    fn drop(&mut self) {
+        unsafe {
+            sys::bulletrsOverlapFilterCallback_delete(self.callback);
+            drop(Box::from_raw(self.data));
+        }
+    }
+}
+
+/// Objects which are not bodies of the world are left to collide.
+unsafe extern "C" fn need_broadphase_collision(
+    user_data: *mut c_void,
+    object0: *const sys::btCollisionObject,
+    object1: *const sys::btCollisionObject,
+) -> bool {
+    let data = &mut *(user_data as *mut FilterData);
+    let bodies = match data.bodies.upgrade() {
+        Some(bodies) => bodies,
+        None => return true,
+    };
+    let keys = {
+        let bodies = bodies.borrow();
+        (bodies.find(object0), bodies.find(object1))
+    };
+    match keys {
+        (Some((index0, generation0)), Some((index1, generation1))) => {
+            let body0 = RigidBodyHandle::new(data.bodies.clone(), index0, generation0);
+            let body1 = RigidBodyHandle::new(data.bodies.clone(), index1, generation1);
+            let filter = &mut data.filter;
+            abort_on_panic(|| filter(&body0, &body1))
+        }
+        _ => true,
+    }
+}
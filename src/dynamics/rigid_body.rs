@@ -1,7 +1,20 @@
 use sys;
 use bullet_vector3::BulletVector3;
-use collision::collision_shapes::Shape;
-use mint::{Vector3, Vector4};
+use collision::broadphase_collision::CollisionGroup;
+use collision::collision_shapes::{Shape, SharedShape};
+use dynamics::body_storage::BodyStorage;
+use dynamics::motion_state::{self, MotionState, MotionStateBox};
+use dynamics::rigid_body_builder::RigidBodyBuilder;
+use errors::Error;
+use mint::{RowMatrix3, Vector3, Vector4};
+use std::any::Any;
+use std::cell::{Ref, RefCell};
+use std::fmt;
+use std::ops::Deref;
+use std::rc::{Rc, Weak};
+
+/// Boxed twice to keep user pointer thin, inner box carries data's type.
+type UserDataBox = Box<Box<Any>>;
 
 #[repr(u8)]
 pub enum ActivationState {
@@ -24,55 +37,158 @@ pub enum ActivationState {
     WantsDeactivation = 3,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BodyType {
+    /// Moved by the simulation.
+    Dynamic,
+    /// Moved by user with kinematic targets, pushes dynamic bodies but is not affected by them.
+    /// Has zero mass and never sleeps.
+    Kinematic,
+    /// Never moves, has zero mass.
+    Static,
+}
+
+bit_flags! {
+    /// Collision flags of bodies, except static and kinematic flags controlled by BodyType.
+    pub struct CollisionFlags(i32);
+}
+
+impl CollisionFlags {
+    /// Body is reported in collisions, but does not push other bodies.
+    pub const NO_CONTACT_RESPONSE: CollisionFlags =
+        CollisionFlags(sys::btCollisionObject_CollisionFlags_CF_NO_CONTACT_RESPONSE as i32);
+    /// Call global contact added callback for contacts of this body.
+    pub const CUSTOM_MATERIAL_CALLBACK: CollisionFlags =
+        CollisionFlags(sys::btCollisionObject_CollisionFlags_CF_CUSTOM_MATERIAL_CALLBACK as i32);
+    pub const CHARACTER_OBJECT: CollisionFlags =
+        CollisionFlags(sys::btCollisionObject_CollisionFlags_CF_CHARACTER_OBJECT as i32);
+    /// Skip this body in bullet's debug drawing.
+    pub const DISABLE_VISUALIZE_OBJECT: CollisionFlags =
+        CollisionFlags(sys::btCollisionObject_CollisionFlags_CF_DISABLE_VISUALIZE_OBJECT as i32);
+
+    const BODY_TYPE_FLAGS: i32 = (sys::btCollisionObject_CollisionFlags_CF_STATIC_OBJECT
+        | sys::btCollisionObject_CollisionFlags_CF_KINEMATIC_OBJECT)
+        as i32;
+}
+
 pub struct RigidBody {
     rigid_body: Box<sys::btRigidBody>,
-    shape: Box<Shape>,
-    motion_state: Box<sys::btDefaultMotionState>,
+    shape: SharedShape,
+    motion_state: *mut sys::bulletrsMotionState,
+    user_motion_state: Option<MotionStateBox>,
+    wake_on_force: bool,
     construction_info: Box<sys::btRigidBody_btRigidBodyConstructionInfo>,
 }
 
 impl RigidBody {
-    pub fn new<T1: Into<Vector3<f64>>, T2: Into<Vector3<f64>>, T3: Into<Vector4<f64>>>(
+    /// Shape may be either owned `Shape` or `SharedShape` used by other bodies.
+    /// Use RigidBodyBuilder to set other construction parameters.
+    pub fn new<S, T1, T2, T3>(
         mass: f64,
         inertia: T1,
-        shape: Shape,
+        shape: S,
         translation: T2,
         orientation: T3,
+    ) -> RigidBody
+    where
+        S: Into<SharedShape>,
+        T1: Into<Vector3<f64>>,
+        T2: Into<Vector3<f64>>,
+        T3: Into<Vector4<f64>>,
+    {
+        RigidBodyBuilder::new(shape)
+            .mass(mass)
+            .inertia(inertia)
+            .position(translation)
+            .orientation(orientation)
+            .build()
+    }
+
+    /// Motion state and collision shape of construction info are filled here.
+    pub(crate) fn from_construction_info(
+        shape: SharedShape,
+        transform: sys::btTransform,
+        mut construction_info: sys::btRigidBody_btRigidBodyConstructionInfo,
     ) -> RigidBody {
-        let mut inertia: BulletVector3 = inertia.into().into();
-        let shape_box = Box::new(shape);
-        let translation: BulletVector3 = translation.into().into();
-        let orientation: [f64; 4] = orientation.into().into();
-        let transform = unsafe {
-            sys::btTransform::new1(
-                &orientation as *const _ as *const _,
-                &translation as *const _ as *const _,
-            )
+        // Bullet simulates the body at its center of mass, motion state keeps
+        // the user facing transform
+        let center_of_mass_offset = match shape.principal_transform() {
+            Some(principal) => unsafe { principal.inverse() },
+            None => unsafe { *sys::btTransform::getIdentity() },
         };
-        let mut motion_state_box = Box::new(unsafe {
-            sys::btDefaultMotionState::new(&transform as *const _, sys::btTransform::getIdentity())
-        });
-
-        let construction_info_box = Box::new(unsafe {
-            sys::btRigidBody_btRigidBodyConstructionInfo::new(
-                mass,
-                &mut *motion_state_box as *mut _ as *mut _,
-                shape_box.as_ptr(),
-                inertia.0.as_mut_ptr() as *mut _,
-            )
-        });
+        let motion_state = unsafe {
+            sys::bulletrsMotionState_new(&transform as *const _, &center_of_mass_offset as *const _)
+        };
+
+        construction_info.m_motionState = motion_state as *mut _;
+        construction_info.m_collisionShape = shape.as_ptr();
+        let construction_info_box = Box::new(construction_info);
         RigidBody {
             rigid_body: Box::new(unsafe {
                 sys::btRigidBody::new(&*construction_info_box as *const _)
             }),
-            shape: shape_box,
-            motion_state: motion_state_box,
+            shape,
+            motion_state,
+            user_motion_state: None,
+            wake_on_force: true,
             construction_info: construction_info_box,
         }
     }
 
-    pub(crate) unsafe fn motion_state_ptr(&self) -> *mut sys::btDefaultMotionState {
-        &*self.motion_state as *const _ as *mut _
+    pub fn shape(&self) -> &SharedShape {
+        &self.shape
+    }
+
+    /// Change body's shape in place, then recalculate its inertia keeping its mass.
+    /// Returns Error::ShapeInUse if the shape is also used by other bodies,
+    /// compound shapes or SharedShape clones.
+    pub fn update_shape<F, R>(&mut self, update: F) -> Result<R, Error>
+    where
+        F: FnOnce(&mut Shape) -> R,
+    {
+        let result = update(SharedShape::get_mut(&mut self.shape).ok_or(Error::ShapeInUse)?);
+        let body = unsafe { self.as_ptr() };
+        let inverse_mass = unsafe { sys::btRigidBody_getInvMass(body) };
+        if inverse_mass != 0.0 {
+            let mass = 1.0 / inverse_mass;
+            let inertia: BulletVector3 = self.shape.calculate_local_inertia(mass).into();
+            unsafe {
+                sys::btRigidBody_setMassProps(body, mass, inertia.0.as_ptr() as *const _);
+                sys::btRigidBody_updateInertiaTensor(body);
+            }
+        }
+        Ok(result)
+    }
+
+    /// Body type should be set before adding the body to the world,
+    /// bullet chooses collision filters of the body by its type.
+    pub fn set_body_type(&mut self, body_type: BodyType) {
+        unsafe { set_body_type(self.as_ptr(), body_type) }
+    }
+
+    pub fn body_type(&self) -> BodyType {
+        unsafe { get_body_type(self.as_ptr()) }
+    }
+
+    pub fn set_collision_flags(&mut self, flags: CollisionFlags) {
+        unsafe { set_collision_flags(self.as_ptr(), flags) }
+    }
+
+    /// Forward body's transforms to user's motion state.
+    /// Body is moved to the transform given by the motion state.
+    pub fn set_motion_state<M: MotionState + 'static>(&mut self, user_motion_state: M) {
+        let user_motion_state: MotionStateBox = Box::new(Box::new(user_motion_state));
+        unsafe {
+            motion_state::set_callbacks(self.motion_state, &user_motion_state);
+            sys::btRigidBody_setMotionState(self.as_ptr(), self.motion_state as *mut _);
+            let body = &mut *self.as_ptr();
+            body._base.m_interpolationWorldTransform = body._base.m_worldTransform;
+        }
+        self.user_motion_state = Some(user_motion_state);
+    }
+
+    pub(crate) unsafe fn motion_state_ptr(&self) -> *mut sys::bulletrsMotionState {
+        self.motion_state
     }
 
     pub(crate) unsafe fn as_ptr(&self) -> *mut sys::btRigidBody {
@@ -83,153 +199,639 @@ impl RigidBody {
 impl Drop for RigidBody {
     fn drop(&mut self) {
         unsafe {
-            ::sys::btMotionState_btMotionState_destructor(
-                &mut *self.motion_state as *mut _ as *mut _,
-            );
+            take_user_data(&mut *self.rigid_body as *mut _);
             ::sys::btRigidBody_btRigidBody_destructor(&mut *self.rigid_body as *mut _);
+            ::sys::bulletrsMotionState_delete(self.motion_state);
         }
     }
 }
 
+/// Handle to a body owned by DynamicsWorld.
+/// Handle becomes stale when the body is removed or the world is dropped,
+/// its methods then return Error::BodyDeleted.
+/// While DynamicsWorld::bodies_mut is in use its methods return Error::BodiesBorrowed.
 #[derive(Clone)]
 pub struct RigidBodyHandle {
-    pub(in dynamics) ptr: *mut sys::btRigidBody,
-    motion_state: *mut sys::btDefaultMotionState,
-    temp_transform: sys::btTransform,
+    bodies: Weak<RefCell<BodyStorage>>,
+    index: usize,
+    generation: u32,
 }
 
-impl RigidBodyHandle {
-    pub fn new(ptr: *mut sys::btRigidBody, motion_state: *mut sys::btDefaultMotionState) -> Self {
-        let temp_transform = unsafe { sys::btTransform::new() };
+/// User data of a body borrowed with RigidBodyHandle::get_user_data.
+/// Keeps bodies of the world borrowed, and alive even if the world is dropped.
+pub struct UserDataRef<T: 'static> {
+    // declared before the storage to release the borrow first
+    data: Ref<'static, T>,
+    _bodies: Rc<RefCell<BodyStorage>>,
+}
 
+impl<T: 'static> Deref for UserDataRef<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.data
+    }
+}
+
+impl<T: fmt::Debug + 'static> fmt::Debug for UserDataRef<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.data.fmt(f)
+    }
+}
+
+impl RigidBodyHandle {
+    pub(crate) fn new(bodies: Weak<RefCell<BodyStorage>>, index: usize, generation: u32) -> Self {
         RigidBodyHandle {
-            ptr,
-            motion_state,
-            temp_transform,
+            bodies,
+            index,
+            generation,
         }
     }
-    pub fn set_restitution(&mut self, restitution: f64) {
+
+    pub(crate) fn index(&self) -> usize {
+        self.index
+    }
+
+    pub(crate) fn generation(&self) -> u32 {
+        self.generation
+    }
+
+    pub(crate) fn body(&self) -> Result<*mut sys::btRigidBody, Error> {
+        let bodies = self.bodies.upgrade().ok_or(Error::BodyDeleted)?;
+        let bodies = bodies.try_borrow().map_err(|_| Error::BodiesBorrowed)?;
+        bodies
+            .get(self.index, self.generation)
+            .map(|body| unsafe { body.as_ptr() })
+    }
+
+    fn motion_state(&self) -> Result<*mut sys::bulletrsMotionState, Error> {
+        let bodies = self.bodies.upgrade().ok_or(Error::BodyDeleted)?;
+        let bodies = bodies.try_borrow().map_err(|_| Error::BodiesBorrowed)?;
+        bodies
+            .get(self.index, self.generation)
+            .map(|body| unsafe { body.motion_state_ptr() })
+    }
+
+    pub fn set_restitution(&mut self, restitution: f64) -> Result<(), Error> {
+        let body = self.body()?;
         unsafe {
-            sys::btCollisionObject_setRestitution(self.ptr as *mut _, restitution);
+            sys::btCollisionObject_setRestitution(body as *mut _, restitution);
+        }
+        Ok(())
+    }
+
+    pub fn get_restitution(&self) -> Result<f64, Error> {
+        let body = self.body()?;
+        Ok(unsafe { sys::btCollisionObject_getRestitution(body as *mut _) })
+    }
+
+    /// Mass of the body, 0 for static bodies.
+    pub fn get_mass(&self) -> Result<f64, Error> {
+        let inverse_mass = self.get_inverse_mass()?;
+        if inverse_mass == 0.0 {
+            Ok(0.0)
+        } else {
+            Ok(1.0 / inverse_mass)
         }
     }
 
-    pub fn set_mass(&mut self, mass: f64) {
+    pub fn get_inverse_mass(&self) -> Result<f64, Error> {
+        let body = self.body()?;
+        Ok(unsafe { sys::btRigidBody_getInvMass(body) })
+    }
+
+    /// Diagonal of inertia tensor in body's local space.
+    pub fn get_local_inertia(&self) -> Result<Vector3<f64>, Error> {
+        let body = self.body()?;
+        let inertia = unsafe { sys::btRigidBody_getLocalInertia(body) };
+        Ok(::bullet_vector3::vector_from_slice(&inertia.m_floats[0..3]))
+    }
+
+    /// Override inertia calculated from the shape by set_mass, keeping body's mass.
+    pub fn set_local_inertia<T: Into<Vector3<f64>>>(&mut self, inertia: T) -> Result<(), Error> {
+        let body = self.body()?;
+        let inertia: BulletVector3 = inertia.into().into();
+        let mass = self.get_mass()?;
         unsafe {
-            let shape = sys::btRigidBody_getCollisionShape(self.ptr);
-            let mut inertia: [f64; 4] = ::std::mem::uninitialized();
-            let shape_type = sys::btCollisionShape_getShapeType(shape as * mut _) as u32;
-
-            match shape_type {
-                sys::BroadphaseNativeTypes_STATIC_PLANE_PROXYTYPE => {
-                    sys::btStaticPlaneShape_calculateLocalInertia(
-                        shape as *mut _,
-                        mass,
-                        inertia.as_mut_ptr() as *mut _,
-                    );
-                },
-                sys::BroadphaseNativeTypes_BOX_SHAPE_PROXYTYPE => {
-
-                    sys::btBoxShape_calculateLocalInertia(
-                        shape as *mut _,
-                        mass,
-                        inertia.as_mut_ptr() as *mut _,
-                    );
-                },
-                sys::BroadphaseNativeTypes_CAPSULE_SHAPE_PROXYTYPE => {
-
-                    sys::btCapsuleShape_calculateLocalInertia(
-                        shape as *mut _,
-                        mass,
-                        inertia.as_mut_ptr() as *mut _,
-                    );
-                },
-                sys::BroadphaseNativeTypes_CONVEX_HULL_SHAPE_PROXYTYPE => {
-                    sys::btPolyhedralConvexShape_calculateLocalInertia(
-                        shape as *mut _,
-                        mass,
-                        inertia.as_mut_ptr() as *mut _,
-                    );
-                },
-                sys::BroadphaseNativeTypes_COMPOUND_SHAPE_PROXYTYPE => {
-                    sys::btCompoundShape_calculateLocalInertia(
-                        shape as *mut _,
-                        mass,
-                        inertia.as_mut_ptr() as *mut _,
-                    );
-                },
-                _ => {
-                    unimplemented!()
-                }
-            }
+            sys::btRigidBody_setMassProps(body, mass, inertia.0.as_ptr() as *const _);
+            sys::btRigidBody_updateInertiaTensor(body);
+        }
+        Ok(())
+    }
+
+    /// Inverse of inertia tensor in world space, updated by bullet every simulation step.
+    pub fn get_inverse_inertia_tensor_world(&self) -> Result<RowMatrix3<f64>, Error> {
+        let body = self.body()?;
+        let tensor = unsafe { &*sys::btRigidBody_getInvInertiaTensorWorld(body) };
+        Ok(RowMatrix3 {
+            x: ::bullet_vector3::vector_from_slice(&tensor.m_el[0].m_floats[0..3]),
+            y: ::bullet_vector3::vector_from_slice(&tensor.m_el[1].m_floats[0..3]),
+            z: ::bullet_vector3::vector_from_slice(&tensor.m_el[2].m_floats[0..3]),
+        })
+    }
 
-            sys::btRigidBody_setMassProps(self.ptr as *mut _, mass, inertia.as_ptr() as *const _);
-            sys::btRigidBody_updateInertiaTensor(self.ptr as *mut _);
+    /// Set mass and recalculate local inertia from body's shape.
+    pub fn set_mass(&mut self, mass: f64) -> Result<(), Error> {
+        let body = self.body()?;
+        let inertia: BulletVector3 = {
+            let bodies = self.bodies.upgrade().ok_or(Error::BodyDeleted)?;
+            let bodies = bodies.try_borrow().map_err(|_| Error::BodiesBorrowed)?;
+            let body = bodies.get(self.index, self.generation)?;
+            body.shape().calculate_local_inertia(mass).into()
+        };
+        unsafe {
+            sys::btRigidBody_setMassProps(body, mass, inertia.0.as_ptr() as *const _);
+            sys::btRigidBody_updateInertiaTensor(body);
+        }
+        Ok(())
+    }
+
+    /// Recalculate local inertia from body's shape, keeping its mass.
+    /// Overrides inertia given with set_local_inertia.
+    pub fn update_mass_properties(&mut self) -> Result<(), Error> {
+        let body = self.body()?;
+        let inverse_mass = unsafe { sys::btRigidBody_getInvMass(body) };
+        if inverse_mass != 0.0 {
+            self.set_mass(1.0 / inverse_mass)?;
         }
+        Ok(())
     }
 
-    pub fn set_friction(&mut self, friction: f64) {
+    /// Change body's shape in place, then recalculate its inertia keeping its mass
+    /// and wake the body, see RigidBody::update_shape.
+    /// Bodies of the world should not be accessed from `update`.
+    pub fn update_shape<F, R>(&mut self, update: F) -> Result<R, Error>
+    where
+        F: FnOnce(&mut Shape) -> R,
+    {
+        let bodies = self.bodies.upgrade().ok_or(Error::BodyDeleted)?;
+        let mut bodies = bodies.try_borrow_mut().map_err(|_| Error::BodiesBorrowed)?;
+        let body = bodies.get_mut(self.index, self.generation)?;
+        let result = body.update_shape(update)?;
+        unsafe { sys::btCollisionObject_activate(body.as_ptr() as *mut _, false) };
+        Ok(result)
+    }
+
+    pub fn set_friction(&mut self, friction: f64) -> Result<(), Error> {
+        let body = self.body()?;
+        unsafe {
+            sys::btCollisionObject_setFriction(body as *mut _, friction);
+        }
+        Ok(())
+    }
+
+    pub fn get_friction(&self) -> Result<f64, Error> {
+        let body = self.body()?;
+        Ok(unsafe { sys::btCollisionObject_getFriction(body as *mut _) })
+    }
+
+    /// Friction against rolling, torsional friction around contact normal.
+    pub fn set_rolling_friction(&mut self, friction: f64) -> Result<(), Error> {
+        let body = self.body()?;
+        unsafe {
+            sys::btCollisionObject_setRollingFriction(body as *mut _, friction);
+        }
+        Ok(())
+    }
+
+    pub fn get_rolling_friction(&self) -> Result<f64, Error> {
+        let body = self.body()?;
+        Ok(unsafe { sys::btCollisionObject_getRollingFriction(body as *mut _) })
+    }
+
+    /// Friction against spinning around contact normal.
+    pub fn set_spinning_friction(&mut self, friction: f64) -> Result<(), Error> {
+        let body = self.body()?;
         unsafe {
-            sys::btCollisionObject_setFriction(self.ptr as *mut _, friction);
+            sys::btCollisionObject_setSpinningFriction(body as *mut _, friction);
         }
+        Ok(())
+    }
+
+    pub fn get_spinning_friction(&self) -> Result<f64, Error> {
+        let body = self.body()?;
+        Ok(unsafe { sys::btCollisionObject_getSpinningFriction(body as *mut _) })
     }
 
-    pub fn set_gravity<T: Into<Vector3<f64>>>(&mut self, gravity: T) {
+    /// Damping of linear and angular velocities, both clamped to 0..1 by bullet.
+    pub fn set_damping(&mut self, linear: f64, angular: f64) -> Result<(), Error> {
+        let body = self.body()?;
+        unsafe { sys::btRigidBody_setDamping(body, linear, angular) }
+        Ok(())
+    }
+
+    pub fn get_linear_damping(&self) -> Result<f64, Error> {
+        let body = self.body()?;
+        Ok(unsafe { sys::btRigidBody_getLinearDamping(body) })
+    }
+
+    pub fn get_angular_damping(&self) -> Result<f64, Error> {
+        let body = self.body()?;
+        Ok(unsafe { sys::btRigidBody_getAngularDamping(body) })
+    }
+
+    pub fn get_gravity(&self) -> Result<Vector3<f64>, Error> {
+        let body = self.body()?;
+        let gravity = unsafe { &*sys::btRigidBody_getGravity(body) };
+        Ok(::bullet_vector3::vector_from_slice(&gravity.m_floats[0..3]))
+    }
+
+    pub fn set_gravity<T: Into<Vector3<f64>>>(&mut self, gravity: T) -> Result<(), Error> {
+        let body = self.body()?;
         let gravity: BulletVector3 = gravity.into().into();
         unsafe {
-            sys::btRigidBody_setGravity(self.ptr, gravity.0.as_ptr() as *const _);
+            sys::btRigidBody_setGravity(body, gravity.0.as_ptr() as *const _);
         }
+        Ok(())
     }
 
-    pub fn set_angular_factor<T: Into<Vector3<f64>>>(&mut self, angular_factor: T) {
+    pub fn set_angular_factor<T>(&mut self, angular_factor: T) -> Result<(), Error>
+    where
+        T: Into<Vector3<f64>>,
+    {
+        let body = self.body()?;
         let angular_factor: BulletVector3 = angular_factor.into().into();
         unsafe {
-            sys::btRigidBody_setAngularFactor(self.ptr, angular_factor.0.as_ptr() as *const _);
+            sys::btRigidBody_setAngularFactor(body, angular_factor.0.as_ptr() as *const _);
         }
+        Ok(())
     }
 
-    pub fn set_sleeping_thresholds(&mut self, linear: f64, angular: f64) {
-        unsafe { sys::btRigidBody_setSleepingThresholds(self.ptr, linear, angular) }
+    pub fn get_angular_factor(&self) -> Result<Vector3<f64>, Error> {
+        let body = self.body()?;
+        let angular_factor = unsafe { &*sys::btRigidBody_getAngularFactor(body) };
+        Ok(::bullet_vector3::vector_from_slice(&angular_factor.m_floats[0..3]))
     }
 
-    pub fn set_activation_state(&mut self, activation_state: ActivationState) {
+    /// Scale of linear motion along world axes, zero component locks the axis.
+    pub fn set_linear_factor<T>(&mut self, linear_factor: T) -> Result<(), Error>
+    where
+        T: Into<Vector3<f64>>,
+    {
+        let body = self.body()?;
+        let linear_factor: BulletVector3 = linear_factor.into().into();
         unsafe {
-            sys::btCollisionObject_setActivationState(self.ptr as *mut _, activation_state as i32)
+            sys::btRigidBody_setLinearFactor(body, linear_factor.0.as_ptr() as *const _);
+        }
+        Ok(())
+    }
+
+    pub fn get_linear_factor(&self) -> Result<Vector3<f64>, Error> {
+        let body = self.body()?;
+        let linear_factor = unsafe { &*sys::btRigidBody_getLinearFactor(body) };
+        Ok(::bullet_vector3::vector_from_slice(&linear_factor.m_floats[0..3]))
+    }
+
+    /// Changing type of a body already added to the world does not update its collision
+    /// filters, remove the body and add it again for static bodies to collide correctly.
+    /// Static and kinematic bodies get zero mass, give mass back with set_mass
+    /// when turning them dynamic.
+    pub fn set_body_type(&mut self, body_type: BodyType) -> Result<(), Error> {
+        let body = self.body()?;
+        unsafe { set_body_type(body, body_type) }
+        Ok(())
+    }
+
+    pub fn get_body_type(&self) -> Result<BodyType, Error> {
+        let body = self.body()?;
+        Ok(unsafe { get_body_type(body) })
+    }
+
+    pub fn set_collision_flags(&mut self, flags: CollisionFlags) -> Result<(), Error> {
+        let body = self.body()?;
+        unsafe { set_collision_flags(body, flags) }
+        Ok(())
+    }
+
+    pub fn get_collision_flags(&self) -> Result<CollisionFlags, Error> {
+        let body = self.body()?;
+        let flags = unsafe { sys::btCollisionObject_getCollisionFlags(body as *mut _) };
+        Ok(CollisionFlags(flags & !CollisionFlags::BODY_TYPE_FLAGS))
+    }
+
+    /// Collision filter group, changed with DynamicsWorld::set_collision_filter.
+    pub fn get_collision_group(&self) -> Result<CollisionGroup, Error> {
+        let proxy = self.broadphase_proxy()?;
+        Ok(CollisionGroup::from_bits(unsafe { (*proxy).m_collisionFilterGroup }))
+    }
+
+    /// Groups this body collides with.
+    pub fn get_collision_mask(&self) -> Result<CollisionGroup, Error> {
+        let proxy = self.broadphase_proxy()?;
+        Ok(CollisionGroup::from_bits(unsafe { (*proxy).m_collisionFilterMask }))
+    }
+
+    pub(crate) fn broadphase_proxy(&self) -> Result<*mut sys::btBroadphaseProxy, Error> {
+        let body = self.body()?;
+        let proxy = unsafe { (*body)._base.m_broadphaseHandle };
+        if proxy.is_null() {
+            return Err(Error::NoValue);
         }
+        Ok(proxy)
     }
 
-    pub fn apply_central_impulse<T: Into<Vector3<f64>>>(&mut self, impulse: T) {
+    /// Move kinematic body. Bullet interpolates the body to the target during the next
+    /// simulation step and calculates its velocity, so dynamic bodies are pushed smoothly.
+    pub fn set_kinematic_target<T, T1>(&mut self, position: T, orientation: T1) -> Result<(), Error>
+    where
+        T: Into<Vector3<f64>>,
+        T1: Into<Vector4<f64>>,
+    {
+        let motion_state = self.motion_state()?;
+        let transform = self.center_of_mass_transform(position, orientation)?;
+        unsafe {
+            sys::bulletrsMotionState_setWorldTransform(motion_state, &transform as *const _);
+        }
+        Ok(())
+    }
+
+    /// Enable continuous collision detection to stop fast bodies tunneling through thin ones.
+    /// When the body moves more than `motion_threshold` in one step, a sphere of
+    /// `swept_sphere_radius` is swept along its path and motion is clamped at the first hit.
+    /// The swept sphere should fit inside the body's shape.
+    pub fn enable_ccd(
+        &mut self,
+        motion_threshold: f64,
+        swept_sphere_radius: f64,
+    ) -> Result<(), Error> {
+        let body = self.body()?;
+        unsafe {
+            sys::btCollisionObject_setCcdMotionThreshold(body as *mut _, motion_threshold);
+            sys::btCollisionObject_setCcdSweptSphereRadius(
+                body as *mut _,
+                swept_sphere_radius,
+            );
+        }
+        Ok(())
+    }
+
+    /// Enable continuous collision detection with values derived from bounding sphere
+    /// of body's shape: half of its radius as motion threshold and a fifth as swept sphere radius.
+    pub fn enable_ccd_from_shape(&mut self) -> Result<(), Error> {
+        let body = self.body()?;
+        let mut center: [f64; 4] = [0.0; 4];
+        let mut radius = 0.0;
+        unsafe {
+            sys::btCollisionShape_getBoundingSphere(
+                sys::btRigidBody_getCollisionShape(body) as *mut _,
+                center.as_mut_ptr() as *mut _,
+                &mut radius,
+            );
+        }
+        self.enable_ccd(radius * 0.5, radius * 0.2)
+    }
+
+    pub fn disable_ccd(&mut self) -> Result<(), Error> {
+        self.enable_ccd(0.0, 0.0)
+    }
+
+    /// Motion threshold of continuous collision detection, 0 if disabled.
+    pub fn get_ccd_motion_threshold(&self) -> Result<f64, Error> {
+        let body = self.body()?;
+        Ok(unsafe { sys::btCollisionObject_getCcdMotionThreshold(body as *mut _) })
+    }
+
+    pub fn get_ccd_swept_sphere_radius(&self) -> Result<f64, Error> {
+        let body = self.body()?;
+        Ok(unsafe { sys::btCollisionObject_getCcdSweptSphereRadius(body as *mut _) })
+    }
+
+    pub fn set_sleeping_thresholds(&mut self, linear: f64, angular: f64) -> Result<(), Error> {
+        let body = self.body()?;
+        unsafe { sys::btRigidBody_setSleepingThresholds(body, linear, angular) }
+        Ok(())
+    }
+
+    pub fn set_activation_state(&mut self, activation_state: ActivationState) -> Result<(), Error> {
+        let body = self.body()?;
+        unsafe {
+            sys::btCollisionObject_setActivationState(body as *mut _, activation_state as i32)
+        }
+        Ok(())
+    }
+
+    /// Should forces, torques and impulses wake the body if it is sleeping. True by default.
+    pub fn set_wake_on_force(&mut self, wake: bool) -> Result<(), Error> {
+        let bodies = self.bodies.upgrade().ok_or(Error::BodyDeleted)?;
+        let mut bodies = bodies.try_borrow_mut().map_err(|_| Error::BodiesBorrowed)?;
+        bodies.get_mut(self.index, self.generation)?.wake_on_force = wake;
+        Ok(())
+    }
+
+    pub fn get_wake_on_force(&self) -> Result<bool, Error> {
+        let bodies = self.bodies.upgrade().ok_or(Error::BodyDeleted)?;
+        let bodies = bodies.try_borrow().map_err(|_| Error::BodiesBorrowed)?;
+        Ok(bodies.get(self.index, self.generation)?.wake_on_force)
+    }
+
+    fn wake_for_force(&self, body: *mut sys::btRigidBody) -> Result<(), Error> {
+        if self.get_wake_on_force()? {
+            unsafe { sys::btCollisionObject_activate(body as *mut _, false) }
+        }
+        Ok(())
+    }
+
+    /// Apply force at the point relative to center of mass, in world space orientation.
+    /// Forces are accumulated until the end of the next simulation step.
+    pub fn apply_force<T, T1>(&mut self, force: T, rel_pos: T1) -> Result<(), Error>
+    where
+        T: Into<Vector3<f64>>,
+        T1: Into<Vector3<f64>>,
+    {
+        let body = self.body()?;
+        let force: BulletVector3 = force.into().into();
+        let rel_pos: BulletVector3 = rel_pos.into().into();
+        self.wake_for_force(body)?;
+        unsafe {
+            sys::btRigidBody_applyForce(
+                body,
+                force.0.as_ptr() as *const _,
+                rel_pos.0.as_ptr() as *const _,
+            );
+        }
+        Ok(())
+    }
+
+    pub fn apply_central_force<T: Into<Vector3<f64>>>(&mut self, force: T) -> Result<(), Error> {
+        let body = self.body()?;
+        let force: BulletVector3 = force.into().into();
+        self.wake_for_force(body)?;
+        unsafe {
+            sys::btRigidBody_applyCentralForce(body, force.0.as_ptr() as *const _);
+        }
+        Ok(())
+    }
+
+    pub fn apply_torque<T: Into<Vector3<f64>>>(&mut self, torque: T) -> Result<(), Error> {
+        let body = self.body()?;
+        let torque: BulletVector3 = torque.into().into();
+        self.wake_for_force(body)?;
+        unsafe {
+            sys::btRigidBody_applyTorque(body, torque.0.as_ptr() as *const _);
+        }
+        Ok(())
+    }
+
+    pub fn apply_torque_impulse<T: Into<Vector3<f64>>>(&mut self, torque: T) -> Result<(), Error> {
+        let body = self.body()?;
+        let torque: BulletVector3 = torque.into().into();
+        self.wake_for_force(body)?;
+        unsafe {
+            sys::btRigidBody_applyTorqueImpulse(body, torque.0.as_ptr() as *const _);
+        }
+        Ok(())
+    }
+
+    /// Apply impulse at the point relative to center of mass, in world space orientation.
+    pub fn apply_impulse<T, T1>(&mut self, impulse: T, rel_pos: T1) -> Result<(), Error>
+    where
+        T: Into<Vector3<f64>>,
+        T1: Into<Vector3<f64>>,
+    {
+        let body = self.body()?;
+        let impulse: BulletVector3 = impulse.into().into();
+        let rel_pos: BulletVector3 = rel_pos.into().into();
+        self.wake_for_force(body)?;
+        unsafe {
+            sys::btRigidBody_applyImpulse(
+                body,
+                impulse.0.as_ptr() as *const _,
+                rel_pos.0.as_ptr() as *const _,
+            );
+        }
+        Ok(())
+    }
+
+    pub fn apply_central_impulse<T>(&mut self, impulse: T) -> Result<(), Error>
+    where
+        T: Into<Vector3<f64>>,
+    {
+        let body = self.body()?;
         let impulse: BulletVector3 = impulse.into().into();
+        self.wake_for_force(body)?;
         unsafe {
-            sys::btRigidBody_applyCentralImpulse(self.ptr, impulse.0.as_ptr() as *const _);
+            sys::btRigidBody_applyCentralImpulse(body, impulse.0.as_ptr() as *const _);
         }
+        Ok(())
     }
 
-    pub fn get_linear_velocity(&self) -> Vector3<f64> {
-        let velocity = unsafe { sys::btRigidBody_getLinearVelocity(self.ptr) };
+    /// Reset accumulated force and torque.
+    pub fn clear_forces(&mut self) -> Result<(), Error> {
+        let body = self.body()?;
+        unsafe { sys::btRigidBody_clearForces(body) }
+        Ok(())
+    }
+
+    /// Force accumulated since the last simulation step.
+    pub fn get_total_force(&self) -> Result<Vector3<f64>, Error> {
+        let body = self.body()?;
+        let force = unsafe { &*sys::btRigidBody_getTotalForce(body) };
+        Ok(::bullet_vector3::vector_from_slice(&force.m_floats[0..3]))
+    }
 
-        ::bullet_vector3::vector_from_slice(unsafe {
+    /// Torque accumulated since the last simulation step.
+    pub fn get_total_torque(&self) -> Result<Vector3<f64>, Error> {
+        let body = self.body()?;
+        let torque = unsafe { &*sys::btRigidBody_getTotalTorque(body) };
+        Ok(::bullet_vector3::vector_from_slice(&torque.m_floats[0..3]))
+    }
+
+    pub fn get_linear_velocity(&self) -> Result<Vector3<f64>, Error> {
+        let body = self.body()?;
+        let velocity = unsafe { sys::btRigidBody_getLinearVelocity(body) };
+
+        Ok(::bullet_vector3::vector_from_slice(unsafe {
             ::std::slice::from_raw_parts(velocity as *const _, 4)
-        })
+        }))
     }
 
     /// Override velocity vector.
-    pub fn reset_linear_velocity<T>(&mut self, velocity: T)
+    pub fn reset_linear_velocity<T>(&mut self, velocity: T) -> Result<(), Error>
     where
         T: Into<Vector3<f64>>,
     {
+        let body = self.body()?;
         let velocity: BulletVector3 = velocity.into().into();
         unsafe {
-            sys::btRigidBody_setLinearVelocity(self.ptr, velocity.0.as_ptr() as *const _);
+            sys::btRigidBody_setLinearVelocity(body, velocity.0.as_ptr() as *const _);
         }
+        Ok(())
+    }
+
+    pub fn get_angular_velocity(&self) -> Result<Vector3<f64>, Error> {
+        let body = self.body()?;
+        let velocity = unsafe { &*sys::btRigidBody_getAngularVelocity(body) };
+        Ok(::bullet_vector3::vector_from_slice(&velocity.m_floats[0..3]))
+    }
+
+    /// Override angular velocity vector.
+    pub fn reset_angular_velocity<T>(&mut self, velocity: T) -> Result<(), Error>
+    where
+        T: Into<Vector3<f64>>,
+    {
+        let body = self.body()?;
+        let velocity: BulletVector3 = velocity.into().into();
+        unsafe {
+            sys::btRigidBody_setAngularVelocity(body, velocity.0.as_ptr() as *const _);
+        }
+        Ok(())
+    }
+
+    /// World space axis aligned bounding box of the body.
+    /// Returns (min, max) corners.
+    pub fn get_aabb(&self) -> Result<(Vector3<f64>, Vector3<f64>), Error> {
+        let body = self.body()?;
+        let mut min: [f64; 4] = [0.0; 4];
+        let mut max: [f64; 4] = [0.0; 4];
+        unsafe {
+            sys::btRigidBody_getAabb(
+                body,
+                min.as_mut_ptr() as *mut _,
+                max.as_mut_ptr() as *mut _,
+            );
+        }
+        Ok((
+            ::bullet_vector3::vector_from_slice(&min[0..3]),
+            ::bullet_vector3::vector_from_slice(&max[0..3]),
+        ))
     }
 
     /// Override position vector and rotation quaternion.
-    pub fn reset_position_and_orientation<T, T1>(&mut self, position: T, orientation: T1)
+    pub fn reset_position_and_orientation<T, T1>(
+        &mut self,
+        position: T,
+        orientation: T1,
+    ) -> Result<(), Error>
     where
         T: Into<Vector3<f64>>,
         T1: Into<Vector4<f64>>,
     {
+        let body = self.body()?;
+        let motion_state = self.motion_state()?;
+        let center_of_mass_transform = self.center_of_mass_transform(position, orientation)?;
+
+        unsafe {
+            sys::bulletrsMotionState_setWorldTransform(
+                motion_state,
+                &center_of_mass_transform as *const _,
+            );
+        }
+
+        unsafe { (*body)._base.m_worldTransform = center_of_mass_transform };
+        Ok(())
+    }
+
+    /// Transform of body's center of mass placed at given position and orientation.
+    fn center_of_mass_transform<T, T1>(
+        &self,
+        position: T,
+        orientation: T1,
+    ) -> Result<sys::btTransform, Error>
+    where
+        T: Into<Vector3<f64>>,
+        T1: Into<Vector4<f64>>,
+    {
+        let motion_state = self.motion_state()?;
         let orientation: [f64; 4] = orientation.into().into();
         let position: BulletVector3 = position.into().into();
         let transform = unsafe {
@@ -239,72 +841,187 @@ impl RigidBodyHandle {
             )
         };
 
+        let mut center_of_mass_transform = unsafe { sys::btTransform::new() };
         unsafe {
-            sys::btDefaultMotionState_setWorldTransform(
-                &*self.motion_state as *const _ as *mut _,
-                &transform as *const _ as *const _,
-            );
+            let offset =
+                (*sys::bulletrsMotionState_getCenterOfMassOffset(motion_state)).inverse();
+            center_of_mass_transform.mult(&transform as *const _, &offset as *const _);
         }
-
-        unsafe { (*self.ptr)._base.m_worldTransform = transform };
+        Ok(center_of_mass_transform)
     }
 
     /// Get position in world space and orientation quaternion
-    pub fn get_world_position_and_orientation(&self) -> (Vector3<f64>, Vector4<f64>) {
-        unsafe {
-            sys::btDefaultMotionState_getWorldTransform(
-                &*self.motion_state as *const _ as *mut _,
-                &self.temp_transform as *const _ as *mut _,
-            );
-        }
-        let origin = unsafe { self.temp_transform.getOrigin1().as_ref().unwrap() };
+    pub fn get_world_position_and_orientation(
+        &self,
+    ) -> Result<(Vector3<f64>, Vector4<f64>), Error> {
+        let motion_state = self.motion_state()?;
+        let transform =
+            unsafe { &*sys::bulletrsMotionState_getGraphicsWorldTransform(motion_state) };
+        let origin = unsafe { transform.getOrigin1().as_ref().unwrap() };
 
-        let rotation = unsafe { self.temp_transform.getRotation() };
+        let rotation = unsafe { transform.getRotation() };
 
-        (
+        Ok((
             ::bullet_vector3::vector_from_slice(&origin.m_floats[0..3]),
             ::bullet_vector3::vector4_from_slice(&rotation._base.m_floats),
-        )
+        ))
     }
 
-    /// Place data on heap as a box and set to rigid body as user pointer
-    pub fn set_user_data<T: 'static>(&mut self, data: T) {
-        let data_box = Box::new(data);
+    /// Place data on heap and set to rigid body as user pointer.
+    /// Previously set data is dropped.
+    /// Returns Error::BodiesBorrowed while user data of any body is borrowed.
+    pub fn set_user_data<T: 'static>(&mut self, data: T) -> Result<(), Error> {
+        let bodies = self.bodies.upgrade().ok_or(Error::BodyDeleted)?;
+        let bodies = bodies.try_borrow_mut().map_err(|_| Error::BodiesBorrowed)?;
+        let body = unsafe { bodies.get(self.index, self.generation)?.as_ptr() };
+        let data_box: UserDataBox = Box::new(Box::new(data));
         unsafe {
+            take_user_data(body);
             sys::btCollisionObject_setUserPointer(
-                self.ptr as *mut _,
+                body as *mut _,
                 Box::into_raw(data_box) as *mut _,
             )
         };
+        Ok(())
+    }
+
+    /// Borrow data from rigidbody's user pointer.
+    /// Returns None if no data was set or data is of other type.
+    /// Bodies of the world stay borrowed until the returned UserDataRef is dropped,
+    /// so the data can't be replaced, taken or dropped with the body meanwhile,
+    /// and the reference can't outlive it:
+    ///
+    /// ```compile_fail
+    /// # use bulletrs::RigidBodyHandle;
+    /// fn escape(body: &RigidBodyHandle) -> &i32 {
+    ///     &*body.get_user_data::<i32>().unwrap().unwrap()
+    /// }
+    /// ```
+    pub fn get_user_data<T: 'static>(&self) -> Result<Option<UserDataRef<T>>, Error> {
+        let bodies = self.bodies.upgrade().ok_or(Error::BodyDeleted)?;
+        // storage is kept alive by the Rc moved into UserDataRef together with the borrow
+        let storage: &'static RefCell<BodyStorage> = unsafe { &*(&*bodies as *const _) };
+        let storage = storage.try_borrow().map_err(|_| Error::BodiesBorrowed)?;
+        let body = unsafe { storage.get(self.index, self.generation)?.as_ptr() };
+        let data = match unsafe { user_data(body) }.and_then(|data| data.downcast_ref::<T>()) {
+            Some(data) => data as *const T,
+            None => return Ok(None),
+        };
+        Ok(Some(UserDataRef {
+            data: Ref::map(storage, |_| unsafe { &*data }),
+            _bodies: bodies,
+        }))
     }
 
-    /// Get data from rigidbody's user pointer
-    /// Getting data from body without previously setted data is fine.
-    /// Getting wrong typed data is unsafe and will cause mem::transmute to wrong pointer type.
-    pub unsafe fn get_user_data<T: 'static>(&self) -> Option<&T> {
-        let pointer = sys::btCollisionObject_getUserPointer(self.ptr as *mut _);
-        let pointer: *const T = ::std::mem::transmute(pointer);
-        return pointer.as_ref();
+    /// Remove data from rigidbody and give it back.
+    /// Returns None and keeps the data if data is of other type.
+    /// Returns Error::BodiesBorrowed while user data of any body is borrowed.
+    pub fn take_user_data<T: 'static>(&mut self) -> Result<Option<T>, Error> {
+        let bodies = self.bodies.upgrade().ok_or(Error::BodyDeleted)?;
+        let bodies = bodies.try_borrow_mut().map_err(|_| Error::BodiesBorrowed)?;
+        let body = unsafe { bodies.get(self.index, self.generation)?.as_ptr() };
+        match unsafe { user_data(body) } {
+            Some(data) if data.is::<T>() => {}
+            _ => return Ok(None),
+        }
+        Ok(unsafe { take_user_data(body) }
+            .and_then(|data| data.downcast::<T>().ok())
+            .map(|data| *data))
     }
 
     /// Set user index. This will not be used in bullet and this is not related to user_data.
-    pub fn set_user_index(&mut self, index: i32) {
-        unsafe { sys::btCollisionObject_setUserIndex(self.ptr as *mut _, index) };
+    pub fn set_user_index(&mut self, index: i32) -> Result<(), Error> {
+        let body = self.body()?;
+        unsafe { sys::btCollisionObject_setUserIndex(body as *mut _, index) };
+        Ok(())
     }
 
     /// Get previously setted user index
     /// If index was not set - will return "-1"
-    pub fn get_user_index(&self) -> i32 {
-        unsafe { sys::btCollisionObject_getUserIndex(self.ptr as *mut _) }
+    pub fn get_user_index(&self) -> Result<i32, Error> {
+        let body = self.body()?;
+        Ok(unsafe { sys::btCollisionObject_getUserIndex(body as *mut _) })
     }
 
     /// Was that rigid_body removed with DynamicsWorld::remove_body()
-    /// Probably may return true also for any not-added bodys
+    /// or its world dropped.
+    /// Bodies can't be checked while DynamicsWorld::bodies_mut is in use,
+    /// only a dropped world is reported then.
     pub fn removed(&self) -> bool {
-        unsafe { (*self.ptr)._base.m_worldArrayIndex == -1 }
+        self.body().err() == Some(Error::BodyDeleted)
+    }
+
+    pub unsafe fn ptr(&self) -> Result<*mut sys::btRigidBody, Error> {
+        self.body()
+    }
+}
+
+unsafe fn set_body_type(body: *mut sys::btRigidBody, body_type: BodyType) {
+    let flags = sys::btCollisionObject_getCollisionFlags(body as *mut _)
+        & !CollisionFlags::BODY_TYPE_FLAGS;
+    let zero: [f64; 4] = [0.0; 4];
+
+    match body_type {
+        BodyType::Dynamic => {
+            sys::btCollisionObject_setCollisionFlags(body as *mut _, flags);
+            sys::btCollisionObject_forceActivationState(
+                body as *mut _,
+                ActivationState::ActiveTag as i32,
+            );
+        }
+        BodyType::Kinematic => {
+            sys::btRigidBody_setMassProps(body, 0.0, zero.as_ptr() as *const _);
+            sys::btCollisionObject_setCollisionFlags(
+                body as *mut _,
+                flags | sys::btCollisionObject_CollisionFlags_CF_KINEMATIC_OBJECT as i32,
+            );
+            sys::btCollisionObject_forceActivationState(
+                body as *mut _,
+                ActivationState::DisableDeactivation as i32,
+            );
+        }
+        BodyType::Static => {
+            sys::btRigidBody_setMassProps(body, 0.0, zero.as_ptr() as *const _);
+            sys::btCollisionObject_setCollisionFlags(
+                body as *mut _,
+                flags | sys::btCollisionObject_CollisionFlags_CF_STATIC_OBJECT as i32,
+            );
+        }
     }
+    sys::btRigidBody_updateInertiaTensor(body);
+}
+
+unsafe fn get_body_type(body: *mut sys::btRigidBody) -> BodyType {
+    let flags = sys::btCollisionObject_getCollisionFlags(body as *mut _) as u32;
+    if flags & sys::btCollisionObject_CollisionFlags_CF_KINEMATIC_OBJECT != 0 {
+        BodyType::Kinematic
+    } else if flags & sys::btCollisionObject_CollisionFlags_CF_STATIC_OBJECT != 0 {
+        BodyType::Static
+    } else {
+        BodyType::Dynamic
+    }
+}
+
+unsafe fn set_collision_flags(body: *mut sys::btRigidBody, flags: CollisionFlags) {
+    let body_type_flags = sys::btCollisionObject_getCollisionFlags(body as *mut _)
+        & CollisionFlags::BODY_TYPE_FLAGS;
+    sys::btCollisionObject_setCollisionFlags(
+        body as *mut _,
+        body_type_flags | (flags.bits() & !CollisionFlags::BODY_TYPE_FLAGS),
+    );
+}
+
+unsafe fn user_data<'a>(body: *mut sys::btRigidBody) -> Option<&'a Any> {
+    let pointer = sys::btCollisionObject_getUserPointer(body as *mut _) as *const Box<Any>;
+    pointer.as_ref().map(|data| &**data)
+}
 
-    pub unsafe fn ptr(&mut self) -> *mut sys::btRigidBody {
-        self.ptr
+unsafe fn take_user_data(body: *mut sys::btRigidBody) -> Option<Box<Any>> {
+    let pointer = sys::btCollisionObject_getUserPointer(body as *mut _) as *mut Box<Any>;
+    if pointer.is_null() {
+        return None;
     }
+    sys::btCollisionObject_setUserPointer(body as *mut _, ::std::ptr::null_mut());
+    let data_box: UserDataBox = Box::from_raw(pointer);
+    Some(*data_box)
 }
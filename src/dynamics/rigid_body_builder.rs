@@ -0,0 +1,182 @@
+use sys;
+use bullet_vector3::BulletVector3;
+use collision::collision_shapes::SharedShape;
+use dynamics::rigid_body::{BodyType, RigidBody};
+use mint::{Vector3, Vector4};
+
+/// Every field of bullet's btRigidBodyConstructionInfo, with bullet's defaults.
+/// If inertia is not given it is calculated from the shape and mass.
+pub struct RigidBodyBuilder {
+    shape: SharedShape,
+    inertia: Option<Vector3<f64>>,
+    position: Vector3<f64>,
+    orientation: Vector4<f64>,
+    body_type: Option<BodyType>,
+    construction_info: sys::btRigidBody_btRigidBodyConstructionInfo,
+}
+
+impl RigidBodyBuilder {
+    /// Shape may be either owned `Shape` or `SharedShape` used by other bodies.
+    /// Body is static with zero mass until mass is set.
+    pub fn new<S: Into<SharedShape>>(shape: S) -> RigidBodyBuilder {
+        let shape: SharedShape = shape.into();
+        let mut inertia: [f64; 4] = [0.0; 4];
+        let construction_info = unsafe {
+            sys::btRigidBody_btRigidBodyConstructionInfo::new(
+                0.0,
+                ::std::ptr::null_mut(),
+                shape.as_ptr(),
+                inertia.as_mut_ptr() as *mut _,
+            )
+        };
+
+        RigidBodyBuilder {
+            shape,
+            inertia: None,
+            position: Vector3 {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+            },
+            orientation: Vector4 {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+                w: 1.0,
+            },
+            body_type: None,
+            construction_info,
+        }
+    }
+
+    pub fn mass(mut self, mass: f64) -> RigidBodyBuilder {
+        self.construction_info.m_mass = mass;
+        self
+    }
+
+    /// Diagonal of inertia tensor in body's local space.
+    pub fn inertia<T: Into<Vector3<f64>>>(mut self, inertia: T) -> RigidBodyBuilder {
+        self.inertia = Some(inertia.into());
+        self
+    }
+
+    pub fn position<T: Into<Vector3<f64>>>(mut self, position: T) -> RigidBodyBuilder {
+        self.position = position.into();
+        self
+    }
+
+    /// Orientation quaternion.
+    pub fn orientation<T: Into<Vector4<f64>>>(mut self, orientation: T) -> RigidBodyBuilder {
+        self.orientation = orientation.into();
+        self
+    }
+
+    /// Kinematic and static bodies get zero mass, collision flags and activation state
+    /// of their type before they are added to the world,
+    /// so bullet puts them to the STATIC collision filter group.
+    pub fn body_type(mut self, body_type: BodyType) -> RigidBodyBuilder {
+        self.body_type = Some(body_type);
+        self
+    }
+
+    pub fn linear_damping(mut self, damping: f64) -> RigidBodyBuilder {
+        self.construction_info.m_linearDamping = damping;
+        self
+    }
+
+    pub fn angular_damping(mut self, damping: f64) -> RigidBodyBuilder {
+        self.construction_info.m_angularDamping = damping;
+        self
+    }
+
+    pub fn friction(mut self, friction: f64) -> RigidBodyBuilder {
+        self.construction_info.m_friction = friction;
+        self
+    }
+
+    pub fn rolling_friction(mut self, friction: f64) -> RigidBodyBuilder {
+        self.construction_info.m_rollingFriction = friction;
+        self
+    }
+
+    pub fn spinning_friction(mut self, friction: f64) -> RigidBodyBuilder {
+        self.construction_info.m_spinningFriction = friction;
+        self
+    }
+
+    pub fn restitution(mut self, restitution: f64) -> RigidBodyBuilder {
+        self.construction_info.m_restitution = restitution;
+        self
+    }
+
+    pub fn linear_sleeping_threshold(mut self, threshold: f64) -> RigidBodyBuilder {
+        self.construction_info.m_linearSleepingThreshold = threshold;
+        self
+    }
+
+    pub fn angular_sleeping_threshold(mut self, threshold: f64) -> RigidBodyBuilder {
+        self.construction_info.m_angularSleepingThreshold = threshold;
+        self
+    }
+
+    /// Extra damping of slow bodies, helps them to fall asleep.
+    pub fn additional_damping(mut self, enabled: bool) -> RigidBodyBuilder {
+        self.construction_info.m_additionalDamping = enabled;
+        self
+    }
+
+    pub fn additional_damping_factor(mut self, factor: f64) -> RigidBodyBuilder {
+        self.construction_info.m_additionalDampingFactor = factor;
+        self
+    }
+
+    pub fn additional_linear_damping_threshold_sqr(mut self, threshold: f64) -> RigidBodyBuilder {
+        self.construction_info.m_additionalLinearDampingThresholdSqr = threshold;
+        self
+    }
+
+    pub fn additional_angular_damping_threshold_sqr(mut self, threshold: f64) -> RigidBodyBuilder {
+        self.construction_info.m_additionalAngularDampingThresholdSqr = threshold;
+        self
+    }
+
+    pub fn additional_angular_damping_factor(mut self, factor: f64) -> RigidBodyBuilder {
+        self.construction_info.m_additionalAngularDampingFactor = factor;
+        self
+    }
+
+    pub fn build(mut self) -> RigidBody {
+        match self.body_type {
+            Some(BodyType::Kinematic) | Some(BodyType::Static) => {
+                self.construction_info.m_mass = 0.0;
+            }
+            _ => {}
+        }
+        let mass = self.construction_info.m_mass;
+        let inertia = match self.inertia {
+            Some(inertia) => inertia,
+            None => self.shape.calculate_local_inertia(mass),
+        };
+        let inertia: BulletVector3 = inertia.into();
+        self.construction_info.m_localInertia = sys::btVector3 {
+            m_floats: inertia.0,
+        };
+
+        let orientation: [f64; 4] = self.orientation.into();
+        let position: BulletVector3 = self.position.into();
+        let transform = unsafe {
+            sys::btTransform::new1(
+                &orientation as *const _ as *const _,
+                &position as *const _ as *const _,
+            )
+        };
+        self.construction_info.m_startWorldTransform = transform;
+
+        let mut body =
+            RigidBody::from_construction_info(self.shape, transform, self.construction_info);
+        if let Some(body_type) = self.body_type {
+            body.set_body_type(body_type);
+        }
+        body
+    }
+}
@@ -5,7 +5,10 @@ pub enum Error {
     ConnectionTerminated,
     CommandFailed,
     NoValue,
-    BodyDeleted
+    BodyDeleted,
+    ShapeInUse,
+    WrongShapeType,
+    BodiesBorrowed,
 }
 
 impl fmt::Display for Error {
@@ -15,6 +18,9 @@ impl fmt::Display for Error {
             Error::CommandFailed => write!(f, "Command failed, real error probably in bullet's log"),
             Error::NoValue => write!(f, "No such value"),
             Error::BodyDeleted => write!(f, "Trying to use deleted body"),
+            Error::ShapeInUse => write!(f, "Shape is used by other bodies or shapes"),
+            Error::WrongShapeType => write!(f, "Not supported by this shape type"),
+            Error::BodiesBorrowed => write!(f, "Bodies of the world are already borrowed"),
         }
     }
 }
@@ -1,21 +1,31 @@
 extern crate mint;
 pub extern crate bulletrs_sys as sys;
 
+#[macro_use]
+mod bit_flags;
 mod collision;
 mod dynamics;
 pub(crate) mod bullet_vector3;
 
 mod errors;
 
-pub use mint::{Point3, Vector3, Vector4};
+pub use mint::{Point3, RowMatrix3, Vector3, Vector4};
 pub use errors::Error;
 
-pub use collision::broadphase_collision::{Broadphase, BroadphaseInterface};
+pub use collision::broadphase_collision::{Broadphase, BroadphaseInterface, CollisionGroup};
 pub use collision::collision_dispatch::{CollisionConfiguration, CollisionDispatcher};
-pub use collision::collision_shapes::{CapsuleAxis, Shape};
+pub use collision::collision_shapes::{CapsuleAxis, ConvexDecompositionParams, ConvexHullOptions,
+                                      ConvexPointCloud, ConvexPolyhedron, GImpactMesh,
+                                      Heightfield, HullOptimization, PointBuffer, Shape,
+                                      ShapeType, SharedShape, TriangleMesh};
 pub use dynamics::constraint_solver::{ConstraintSolver, HingeConstraint};
 pub use dynamics::dynamics_world::{AllRayResultCallback, ClosestRayResultCallback, DynamicsWorld,
                                    RayResultCallback};
-pub use dynamics::rigid_body::{ActivationState, RigidBody, RigidBodyHandle};
+pub use dynamics::body_storage::BodiesMut;
+pub use dynamics::contact_manifold::{ContactManifold, ContactPoint};
+pub use dynamics::motion_state::MotionState;
+pub use dynamics::rigid_body_builder::RigidBodyBuilder;
+pub use dynamics::rigid_body::{ActivationState, BodyType, CollisionFlags, RigidBody,
+                               RigidBodyHandle, UserDataRef};
 
 pub(crate) use bullet_vector3::BulletVector3;
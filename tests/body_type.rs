@@ -0,0 +1,154 @@
+extern crate bulletrs;
+extern crate cgmath;
+
+use cgmath::{Vector3, Vector4};
+
+use bulletrs::*;
+
+#[test()]
+fn static_body_does_not_fall() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+    dynamics_world.set_gravity(Vector3::new(0.0, -10.0, 0.0));
+
+    let shape = Shape::new_box(Vector3::new(1.0, 1.0, 1.0));
+    let mut body = RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(0.0, 10.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    );
+    assert_eq!(body.body_type(), BodyType::Dynamic);
+    body.set_body_type(BodyType::Static);
+    let body = dynamics_world.add_rigid_body(body);
+    assert_eq!(body.get_body_type().unwrap(), BodyType::Static);
+    assert_eq!(body.get_mass().unwrap(), 0.0);
+
+    for _ in 0..10 {
+        dynamics_world.step_simulation(0.1, 0, 0.0);
+    }
+    let (position, _) = body.get_world_position_and_orientation().unwrap();
+    assert_eq!(position.y, 10.0);
+}
+
+#[test()]
+fn kinematic_platform() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+    dynamics_world.set_gravity(Vector3::new(0.0, -10.0, 0.0));
+
+    let shape = Shape::new_box(Vector3::new(2.0, 0.1, 2.0));
+    let mut platform = RigidBody::new(
+        0.0,
+        Vector3::new(0.0, 0.0, 0.0),
+        shape,
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    );
+    platform.set_body_type(BodyType::Kinematic);
+    let mut platform = dynamics_world.add_rigid_body(platform);
+    assert_eq!(platform.get_body_type().unwrap(), BodyType::Kinematic);
+
+    let shape = Shape::new_box(Vector3::new(0.5, 0.5, 0.5));
+    let cargo = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(0.0, 0.6, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+
+    for i in 0..60 {
+        platform.set_kinematic_target(
+            Vector3::new(0.0, i as f64 * 0.05, 0.0),
+            Vector4::new(0.0, 0.0, 0.0, 1.0),
+        ).unwrap();
+        dynamics_world.step_simulation(1.0 / 60.0, 0, 0.0);
+    }
+
+    let (platform_position, _) = platform.get_world_position_and_orientation().unwrap();
+    assert!((platform_position.y - 59.0 * 0.05).abs() < 1e-6);
+    let (cargo_position, _) = cargo.get_world_position_and_orientation().unwrap();
+    assert!(cargo_position.y > 2.0);
+}
+
+#[test()]
+fn collision_flags() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+    dynamics_world.set_gravity(Vector3::new(0.0, -10.0, 0.0));
+
+    let shape = Shape::new_sphere(1.0);
+    let mut body = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    assert_eq!(body.get_collision_flags().unwrap(), CollisionFlags::empty());
+
+    body.set_body_type(BodyType::Kinematic).unwrap();
+    body.set_collision_flags(
+        CollisionFlags::NO_CONTACT_RESPONSE | CollisionFlags::DISABLE_VISUALIZE_OBJECT,
+    ).unwrap();
+    let flags = body.get_collision_flags().unwrap();
+    assert!(flags.contains(CollisionFlags::NO_CONTACT_RESPONSE));
+    assert!(flags.contains(CollisionFlags::DISABLE_VISUALIZE_OBJECT));
+    assert!(!flags.contains(CollisionFlags::CUSTOM_MATERIAL_CALLBACK));
+    assert_eq!(body.get_body_type().unwrap(), BodyType::Kinematic);
+}
+
+#[test()]
+fn kinematic_body_from_builder() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+    dynamics_world.set_gravity(Vector3::new(0.0, -10.0, 0.0));
+
+    let platform = dynamics_world.add_rigid_body(
+        RigidBodyBuilder::new(Shape::new_box(Vector3::new(2.0, 0.1, 2.0)))
+            .mass(1.0)
+            .position(Vector3::new(0.0, 1.0, 0.0))
+            .body_type(BodyType::Kinematic)
+            .build(),
+    );
+    assert_eq!(platform.get_body_type(), Ok(BodyType::Kinematic));
+    assert_eq!(platform.get_mass(), Ok(0.0));
+    assert_eq!(platform.get_collision_group(), Ok(CollisionGroup::STATIC));
+
+    let ball = dynamics_world.add_rigid_body(
+        RigidBodyBuilder::new(Shape::new_sphere(0.5))
+            .mass(1.0)
+            .body_type(BodyType::Dynamic)
+            .build(),
+    );
+    assert_eq!(ball.get_body_type(), Ok(BodyType::Dynamic));
+    assert_eq!(ball.get_collision_group(), Ok(CollisionGroup::DEFAULT));
+
+    for _ in 0..60 {
+        dynamics_world.step_simulation(1.0 / 60.0, 0, 0.0);
+    }
+    let (position, _) = platform.get_world_position_and_orientation().unwrap();
+    assert_eq!(position.y, 1.0);
+}
@@ -27,7 +27,7 @@ fn set_gravity() {
         Vector3::new(0.0, 0.0, 0.0),
         Vector4::new(0.0, 0.0, 0.0, 1.0),
     ));
-    ground_rigid_body.set_restitution(0.95);
+    ground_rigid_body.set_restitution(0.95).unwrap();
 
     let shape = Shape::new_box(Vector3::new(1.0,2.0,0.5));
     let mass = 0.1;
@@ -38,12 +38,12 @@ fn set_gravity() {
         Vector3::new(4.0, 2.0, 0.0),
         Vector4::new(0.0, 0.0, 0.0, 1.0),
     ));
-    capsule.set_angular_factor(Vector3::new(0.0, 0.0, 0.0));
+    capsule.set_angular_factor(Vector3::new(0.0, 0.0, 0.0)).unwrap();
     for _ in 0 .. 10 {
         dynamics_world.step_simulation(0.1, 0, 0.0);
     }
 
-    let (_, orientation) = capsule.get_world_position_and_orientation();
+    let (_, orientation) = capsule.get_world_position_and_orientation().unwrap();
     assert_eq!(orientation.x, 0.0);
     assert_eq!(orientation.y, 0.0);
     assert_eq!(orientation.z, 0.0);
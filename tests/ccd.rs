@@ -0,0 +1,84 @@
+extern crate bulletrs;
+extern crate cgmath;
+
+use cgmath::{Vector3, Vector4};
+
+use bulletrs::*;
+
+/// Shoot a sphere at 500 m/s towards a 2 cm thick wall at x = 0,
+/// returns x coordinate of the sphere after one second.
+fn shoot(ccd: bool) -> f64 {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+    dynamics_world.set_gravity(Vector3::new(0.0, 0.0, 0.0));
+
+    dynamics_world.add_rigid_body(RigidBody::new(
+        0.0,
+        Vector3::new(0.0, 0.0, 0.0),
+        Shape::new_box(Vector3::new(0.01, 2.0, 2.0)),
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+
+    let shape = Shape::new_sphere(0.1);
+    let mut bullet = dynamics_world.add_rigid_body(RigidBody::new(
+        0.1,
+        shape.calculate_local_inertia(0.1),
+        shape,
+        Vector3::new(-5.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    if ccd {
+        bullet.enable_ccd_from_shape().unwrap();
+        assert!(bullet.get_ccd_motion_threshold().unwrap() > 0.0);
+        assert!(bullet.get_ccd_swept_sphere_radius().unwrap() > 0.0);
+    }
+    bullet.reset_linear_velocity(Vector3::new(500.0, 0.0, 0.0)).unwrap();
+
+    for _ in 0..60 {
+        dynamics_world.step_simulation(1.0 / 60.0, 0, 0.0);
+    }
+
+    let (position, _) = bullet.get_world_position_and_orientation().unwrap();
+    position.x
+}
+
+#[test()]
+fn fast_sphere_tunnels_without_ccd() {
+    assert!(shoot(false) > 0.0);
+}
+
+#[test()]
+fn fast_sphere_stopped_with_ccd() {
+    assert!(shoot(true) < 0.0);
+}
+
+#[test()]
+fn disable_ccd() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+    let shape = Shape::new_sphere(1.0);
+    let mut body = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+
+    body.enable_ccd(0.5, 0.2).unwrap();
+    assert_eq!(body.get_ccd_motion_threshold().unwrap(), 0.5);
+    assert_eq!(body.get_ccd_swept_sphere_radius().unwrap(), 0.2);
+    body.disable_ccd().unwrap();
+    assert_eq!(body.get_ccd_motion_threshold().unwrap(), 0.0);
+}
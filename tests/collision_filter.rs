@@ -0,0 +1,201 @@
+extern crate bulletrs;
+extern crate cgmath;
+
+use cgmath::{Vector3, Vector4};
+
+use bulletrs::*;
+
+#[test()]
+fn groups_and_masks() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    let ghost_group = CollisionGroup::from_bits(64);
+    let mut ghost_mask = CollisionGroup::ALL;
+    ghost_mask.remove(CollisionGroup::STATIC);
+    assert_eq!(ghost_mask, CollisionGroup::ALL & !CollisionGroup::STATIC);
+
+    dynamics_world.add_rigid_body(RigidBody::new(
+        0.0,
+        Vector3::new(0.0, 0.0, 0.0),
+        Shape::new_plane(Vector3::new(0.0, 1.0, 0.0), 0.0),
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    let shape = Shape::new_sphere(1.0);
+    let body = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(0.0, 2.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    let shape = Shape::new_sphere(1.0);
+    let ghost = dynamics_world.add_rigid_body_with_filter(
+        RigidBody::new(
+            1.0,
+            shape.calculate_local_inertia(1.0),
+            shape,
+            Vector3::new(5.0, 2.0, 0.0),
+            Vector4::new(0.0, 0.0, 0.0, 1.0),
+        ),
+        ghost_group,
+        ghost_mask,
+    );
+    assert_eq!(ghost.get_collision_group(), Ok(ghost_group));
+    assert_eq!(body.get_collision_group(), Ok(CollisionGroup::DEFAULT));
+    assert_eq!(body.get_collision_mask(), Ok(CollisionGroup::ALL));
+
+    for _ in 0..60 {
+        dynamics_world.step_simulation(1.0 / 60.0, 0, 1.0 / 60.0);
+    }
+
+    assert!(body.get_world_position_and_orientation().unwrap().0.y > 0.5);
+    assert!(ghost.get_world_position_and_orientation().unwrap().0.y < 0.0);
+}
+
+#[test()]
+fn change_filter() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    dynamics_world.add_rigid_body(RigidBody::new(
+        0.0,
+        Vector3::new(0.0, 0.0, 0.0),
+        Shape::new_plane(Vector3::new(0.0, 1.0, 0.0), 0.0),
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    let shape = Shape::new_sphere(1.0);
+    let body = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(0.0, 2.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+
+    for _ in 0..60 {
+        dynamics_world.step_simulation(1.0 / 60.0, 0, 1.0 / 60.0);
+    }
+    assert!(body.get_world_position_and_orientation().unwrap().0.y > 0.5);
+
+    dynamics_world
+        .set_collision_filter(&body, CollisionGroup::DEBRIS, CollisionGroup::DEBRIS)
+        .unwrap();
+    assert_eq!(body.get_collision_mask(), Ok(CollisionGroup::DEBRIS));
+
+    for _ in 0..60 {
+        dynamics_world.step_simulation(1.0 / 60.0, 0, 1.0 / 60.0);
+    }
+    assert!(body.get_world_position_and_orientation().unwrap().0.y < 0.0);
+}
+
+#[test()]
+fn enable_collision_without_moving() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+    dynamics_world.set_gravity(Vector3::new(0.0, 0.0, 0.0));
+
+    dynamics_world.add_rigid_body(RigidBody::new(
+        0.0,
+        Vector3::new(0.0, 0.0, 0.0),
+        Shape::new_plane(Vector3::new(0.0, 1.0, 0.0), 0.0),
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    let shape = Shape::new_sphere(1.0);
+    let body = dynamics_world.add_rigid_body_with_filter(
+        RigidBody::new(
+            1.0,
+            shape.calculate_local_inertia(1.0),
+            shape,
+            Vector3::new(0.0, 0.5, 0.0),
+            Vector4::new(0.0, 0.0, 0.0, 1.0),
+        ),
+        CollisionGroup::DEBRIS,
+        CollisionGroup::DEBRIS,
+    );
+
+    for _ in 0..60 {
+        dynamics_world.step_simulation(1.0 / 60.0, 0, 1.0 / 60.0);
+    }
+    assert_eq!(body.get_world_position_and_orientation().unwrap().0.y, 0.5);
+
+    // the body overlaps the ground without moving, its pair has to be found again
+    dynamics_world
+        .set_collision_filter(&body, CollisionGroup::DEFAULT, CollisionGroup::ALL)
+        .unwrap();
+    for _ in 0..60 {
+        dynamics_world.step_simulation(1.0 / 60.0, 0, 1.0 / 60.0);
+    }
+    assert!(body.get_world_position_and_orientation().unwrap().0.y > 0.5);
+}
+
+#[test()]
+fn ray_filter() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    let shape = Shape::new_sphere(1.0);
+    dynamics_world.add_rigid_body_with_filter(
+        RigidBody::new(
+            1.0,
+            shape.calculate_local_inertia(1.0),
+            shape,
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector4::new(0.0, 0.0, 0.0, 1.0),
+        ),
+        CollisionGroup::DEBRIS,
+        CollisionGroup::ALL,
+    );
+    let shape = Shape::new_sphere(1.0);
+    let character = dynamics_world.add_rigid_body_with_filter(
+        RigidBody::new(
+            1.0,
+            shape.calculate_local_inertia(1.0),
+            shape,
+            Vector3::new(5.0, 0.0, 0.0),
+            Vector4::new(0.0, 0.0, 0.0, 1.0),
+        ),
+        CollisionGroup::CHARACTER,
+        CollisionGroup::ALL,
+    );
+
+    let mut callback = ClosestRayResultCallback::new(
+        Vector3::new(-10.0, 0.0, 0.0),
+        Vector3::new(10.0, 0.0, 0.0),
+    );
+    callback.set_collision_filter(CollisionGroup::DEFAULT, CollisionGroup::CHARACTER);
+    let result = dynamics_world.raytest(callback);
+    let hit = result.intersections()[0].rigidbody().unwrap();
+    assert_eq!(hit.get_collision_group(), character.get_collision_group());
+
+    let mut callback = AllRayResultCallback::new(
+        Vector3::new(-10.0, 0.0, 0.0),
+        Vector3::new(10.0, 0.0, 0.0),
+    );
+    callback.set_collision_filter(CollisionGroup::DEFAULT, CollisionGroup::STATIC);
+    let result = dynamics_world.raytest(callback);
+    assert_eq!(result.intersections().len(), 0);
+}
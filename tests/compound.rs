@@ -0,0 +1,210 @@
+extern crate bulletrs;
+extern crate cgmath;
+
+use cgmath::{Vector3, Vector4};
+
+use bulletrs::*;
+
+fn identity() -> Vector4<f64> {
+    Vector4::new(0.0, 0.0, 0.0, 1.0)
+}
+
+#[test()]
+fn add_and_remove_children() {
+    let mut compound = Shape::new_compound(vec![
+        (
+            Shape::new_box(Vector3::new(0.5, 0.5, 0.5)),
+            Vector3::new(0.0, 0.0, 0.0),
+            identity(),
+        ),
+    ]);
+    compound.set_margin(0.0);
+    assert_eq!(compound.child_count(), 1);
+
+    compound
+        .add_child(
+            Shape::new_sphere(1.0),
+            Vector3::new(5.0, 0.0, 0.0),
+            identity(),
+        )
+        .unwrap();
+    assert_eq!(compound.child_count(), 2);
+    let (position, _) = compound.child_transform(1).unwrap();
+    assert_eq!(position.x, 5.0);
+    let (_, max) = compound.aabb(Vector3::new(0.0, 0.0, 0.0), identity());
+    assert!(max.x >= 6.0);
+
+    compound
+        .update_child_transform(1, Vector3::new(-5.0, 0.0, 0.0), identity())
+        .unwrap();
+    let (position, _) = compound.child_transform(1).unwrap();
+    assert_eq!(position.x, -5.0);
+    let (min, max) = compound.aabb(Vector3::new(0.0, 0.0, 0.0), identity());
+    assert!(min.x <= -6.0);
+    assert!(max.x < 1.0);
+
+    let removed = compound.remove_child(1).unwrap();
+    assert_eq!(removed.shape_type(), ShapeType::Sphere);
+    assert_eq!(compound.child_count(), 1);
+    let (min, _) = compound.aabb(Vector3::new(0.0, 0.0, 0.0), identity());
+    assert!(min.x > -1.0);
+
+    assert_eq!(compound.remove_child(1).err(), Some(Error::NoValue));
+    assert!(compound.child_transform(1).is_none());
+}
+
+#[test()]
+fn not_a_compound() {
+    let mut shape = Shape::new_sphere(1.0);
+    assert_eq!(
+        shape.add_child(Shape::new_sphere(1.0), Vector3::new(0.0, 0.0, 0.0), identity()),
+        Err(Error::WrongShapeType)
+    );
+    assert_eq!(shape.child_count(), 0);
+    assert!(shape.child_shape(0).is_none());
+}
+
+#[test()]
+fn attach_part_to_body() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+    dynamics_world.set_gravity(Vector3::new(0.0, -10.0, 0.0));
+
+    let compound = Shape::new_compound(vec![
+        (
+            Shape::new_box(Vector3::new(0.5, 0.5, 0.5)),
+            Vector3::new(0.0, 0.0, 0.0),
+            identity(),
+        ),
+    ]);
+    let mut body = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        compound.calculate_local_inertia(1.0),
+        compound,
+        Vector3::new(0.0, 10.0, 0.0),
+        identity(),
+    ));
+    let inertia = body.get_local_inertia().unwrap();
+
+    let attached = body.update_shape(|shape| {
+        shape.add_child(
+            Shape::new_box(Vector3::new(0.5, 0.5, 0.5)),
+            Vector3::new(0.0, 1.0, 0.0),
+            identity(),
+        )
+    });
+    assert_eq!(attached, Ok(Ok(())));
+    let attached_inertia = body.get_local_inertia().unwrap();
+    assert!(attached_inertia.x > inertia.x);
+    assert!(attached_inertia.z > inertia.z);
+    assert_eq!(body.get_mass(), Ok(1.0));
+
+    body.update_shape(|shape| shape.remove_child(1)).unwrap().unwrap();
+    let detached_inertia = body.get_local_inertia().unwrap();
+    assert!((detached_inertia.x - inertia.x).abs() < 1e-6);
+
+    for _ in 0..10 {
+        dynamics_world.step_simulation(0.1, 0, 0.1);
+    }
+    let (position, _) = body.get_world_position_and_orientation().unwrap();
+    assert!(position.y < 10.0);
+}
+
+#[test()]
+fn failed_change_keeps_principal_inertia() {
+    let (mut compound, _) = Shape::new_compound_with_masses(vec![
+        (
+            Shape::new_box(Vector3::new(0.5, 0.5, 0.5)),
+            1.0,
+            Vector3::new(-1.0, 0.0, 0.0),
+            identity(),
+        ),
+        (
+            Shape::new_box(Vector3::new(0.5, 0.5, 0.5)),
+            3.0,
+            Vector3::new(1.0, 0.0, 0.0),
+            identity(),
+        ),
+    ]);
+    let inertia = compound.calculate_local_inertia(4.0);
+
+    assert_eq!(compound.remove_child(2).err(), Some(Error::NoValue));
+    assert_eq!(
+        compound.update_child_transform(2, Vector3::new(0.0, 0.0, 0.0), identity()),
+        Err(Error::NoValue)
+    );
+    assert_eq!(compound.calculate_local_inertia(4.0), inertia);
+
+    compound.remove_child(1).unwrap();
+    assert!(compound.calculate_local_inertia(4.0) != inertia);
+}
+
+#[test()]
+fn update_shape_before_adding_body() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    let compound = Shape::new_compound(vec![
+        (
+            Shape::new_box(Vector3::new(0.5, 0.5, 0.5)),
+            Vector3::new(0.0, 0.0, 0.0),
+            identity(),
+        ),
+    ]);
+    let mut body = RigidBody::new(
+        1.0,
+        compound.calculate_local_inertia(1.0),
+        compound,
+        Vector3::new(0.0, 0.0, 0.0),
+        identity(),
+    );
+    let attached = body.update_shape(|shape| {
+        shape.add_child(
+            Shape::new_box(Vector3::new(0.5, 0.5, 0.5)),
+            Vector3::new(0.0, 1.0, 0.0),
+            identity(),
+        )
+    });
+    assert_eq!(attached, Ok(Ok(())));
+
+    let expected = Shape::new_compound(vec![
+        (
+            Shape::new_box(Vector3::new(0.5, 0.5, 0.5)),
+            Vector3::new(0.0, 0.0, 0.0),
+            identity(),
+        ),
+        (
+            Shape::new_box(Vector3::new(0.5, 0.5, 0.5)),
+            Vector3::new(0.0, 1.0, 0.0),
+            identity(),
+        ),
+    ]).calculate_local_inertia(1.0);
+    let body = dynamics_world.add_rigid_body(body);
+    let inertia = body.get_local_inertia().unwrap();
+    assert!((inertia.x - expected.x).abs() < 1e-6);
+    assert!((inertia.y - expected.y).abs() < 1e-6);
+    assert_eq!(body.get_mass(), Ok(1.0));
+
+    let shared = SharedShape::new(Shape::new_sphere(1.0));
+    let mut body = RigidBody::new(
+        1.0,
+        shared.calculate_local_inertia(1.0),
+        shared.clone(),
+        Vector3::new(0.0, 0.0, 0.0),
+        identity(),
+    );
+    assert_eq!(
+        body.update_shape(|shape| shape.set_margin(0.1)),
+        Err(Error::ShapeInUse)
+    );
+}
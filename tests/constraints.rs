@@ -59,17 +59,17 @@ fn hinge() {
         Vector3::from([1.0, 0.0, 0.0]),
         Vector3::from([1.0, 0.0, 0.0]),
         false
-    );
-    dynamics_world.add_constraint(hinge, true);
+    ).unwrap();
+    dynamics_world.add_constraint(hinge, true).unwrap();
 
     for _ in 0..1000 {
-        let (_position, _) = body_b.get_world_position_and_orientation();
+        let (_position, _) = body_b.get_world_position_and_orientation().unwrap();
 
         //println!("{}", _position.y);
 
         dynamics_world.step_simulation(0.1, 0, 0.0);
     }
-    let (position, _) = body_b.get_world_position_and_orientation();
+    let (position, _) = body_b.get_world_position_and_orientation().unwrap();
 
     assert!(position.y >= -100.0);
 
@@ -0,0 +1,98 @@
+extern crate bulletrs;
+extern crate cgmath;
+
+use cgmath::{Vector3, Vector4};
+
+use bulletrs::*;
+
+#[test()]
+fn resting_contact() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    dynamics_world.set_gravity(Vector3::new(0.0, -10.0, 0.0));
+
+    let mut ground = dynamics_world.add_rigid_body(RigidBody::new(
+        0.0,
+        Vector3::new(0.0, 0.0, 0.0),
+        Shape::new_plane(Vector3::new(0.0, 1.0, 0.0), 0.0),
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    ground.set_user_index(1).unwrap();
+    let shape = Shape::new_sphere(1.0);
+    let mut body = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(0.0, 2.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    body.set_user_index(2).unwrap();
+    assert_eq!(dynamics_world.contact_manifolds().count(), 0);
+
+    for _ in 0..120 {
+        dynamics_world.step_simulation(1.0 / 60.0, 0, 1.0 / 60.0);
+    }
+
+    let manifolds = dynamics_world.contact_manifolds().collect::<Vec<_>>();
+    assert_eq!(manifolds.len(), 1);
+    let manifold = &manifolds[0];
+    let mut indices = vec![
+        manifold.body_a.get_user_index().unwrap(),
+        manifold.body_b.get_user_index().unwrap(),
+    ];
+    indices.sort();
+    assert_eq!(indices, vec![1, 2]);
+
+    assert!(manifold.points.len() > 0);
+    for point in &manifold.points {
+        assert!(point.distance.abs() < 0.05);
+        assert!(point.normal.y.abs() > 0.99);
+        assert!(point.position_on_a.y.abs() < 0.05);
+        assert!(point.position_on_b.y.abs() < 0.05);
+        assert!(point.applied_impulse > 0.0);
+        assert!(point.combined_friction >= 0.0);
+    }
+}
+
+#[test()]
+fn removed_body_has_no_contacts() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    dynamics_world.set_gravity(Vector3::new(0.0, -10.0, 0.0));
+
+    dynamics_world.add_rigid_body(RigidBody::new(
+        0.0,
+        Vector3::new(0.0, 0.0, 0.0),
+        Shape::new_plane(Vector3::new(0.0, 1.0, 0.0), 0.0),
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    let shape = Shape::new_sphere(1.0);
+    let body = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(0.0, 2.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    for _ in 0..120 {
+        dynamics_world.step_simulation(1.0 / 60.0, 0, 1.0 / 60.0);
+    }
+    assert_eq!(dynamics_world.contact_manifolds().count(), 1);
+
+    dynamics_world.remove_body(&body).unwrap();
+    assert_eq!(dynamics_world.contact_manifolds().count(), 0);
+}
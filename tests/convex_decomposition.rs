@@ -0,0 +1,94 @@
+extern crate bulletrs;
+extern crate cgmath;
+
+use cgmath::{Vector3, Vector4};
+
+use bulletrs::*;
+
+/// Two unit cubes, 4 units apart along X.
+fn two_cubes() -> (Vec<Vector3<f64>>, Vec<u32>) {
+    let mut vertices = vec![];
+    let mut indices = vec![];
+    let faces: [[u32; 4]; 6] = [
+        [0, 2, 3, 1],
+        [4, 5, 7, 6],
+        [0, 1, 5, 4],
+        [2, 6, 7, 3],
+        [0, 4, 6, 2],
+        [1, 3, 7, 5],
+    ];
+    for offset in [-2.0, 2.0].iter() {
+        let first = vertices.len() as u32;
+        for i in 0..8 {
+            vertices.push(Vector3::new(
+                offset + if i & 4 != 0 { 0.5 } else { -0.5 },
+                if i & 2 != 0 { 0.5 } else { -0.5 },
+                if i & 1 != 0 { 0.5 } else { -0.5 },
+            ));
+        }
+        for face in faces.iter() {
+            indices.extend_from_slice(&[first + face[0], first + face[1], first + face[2]]);
+            indices.extend_from_slice(&[first + face[0], first + face[2], first + face[3]]);
+        }
+    }
+    (vertices, indices)
+}
+
+#[test()]
+fn convex_decomposition() {
+    let (vertices, indices) = two_cubes();
+    let shape = Shape::new_convex_decomposition(
+        &vertices,
+        &indices,
+        ConvexDecompositionParams {
+            resolution: 20000,
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(shape.shape_type(), ShapeType::Compound);
+    assert!(shape.child_count() >= 2);
+    for index in 0..shape.child_count() {
+        let points = shape.child_shape(index).unwrap().convex_hull_points().unwrap();
+        assert!(points.len() <= 64);
+    }
+
+    let (min, max) = shape.aabb(Vector3::new(0.0, 0.0, 0.0), Vector4::new(0.0, 0.0, 0.0, 1.0));
+    assert!(min.x < -2.0 && max.x > 2.0);
+}
+
+#[test()]
+fn max_hulls() {
+    let (vertices, indices) = two_cubes();
+    let shape = Shape::new_convex_decomposition(
+        &vertices,
+        &indices,
+        ConvexDecompositionParams {
+            resolution: 20000,
+            max_hulls: 1,
+            max_vertices_per_hull: 16,
+        },
+    );
+
+    assert_eq!(shape.child_count(), 1);
+}
+
+#[test()]
+fn max_vertices_per_hull() {
+    let (vertices, indices) = two_cubes();
+    let shape = Shape::new_convex_decomposition(
+        &vertices,
+        &indices,
+        ConvexDecompositionParams {
+            resolution: 20000,
+            max_hulls: 1,
+            max_vertices_per_hull: 6,
+        },
+    );
+
+    assert_eq!(shape.child_count(), 1);
+    for index in 0..shape.child_count() {
+        let points = shape.child_shape(index).unwrap().convex_hull_points().unwrap();
+        assert!(points.len() >= 4 && points.len() <= 6);
+    }
+}
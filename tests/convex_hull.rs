@@ -0,0 +1,76 @@
+extern crate bulletrs;
+extern crate cgmath;
+
+use cgmath::Vector3;
+
+use bulletrs::*;
+
+fn cube_with_interior_points() -> Vec<Vector3<f64>> {
+    let mut points = vec![];
+    for x in 0..5 {
+        for y in 0..5 {
+            for z in 0..5 {
+                points.push(Vector3::new(
+                    x as f64 / 2.0 - 1.0,
+                    y as f64 / 2.0 - 1.0,
+                    z as f64 / 2.0 - 1.0,
+                ));
+            }
+        }
+    }
+    points
+}
+
+#[test()]
+fn hull_computer() {
+    let shape = Shape::new_convex_hull_with_options(
+        &cube_with_interior_points(),
+        ConvexHullOptions {
+            optimization: HullOptimization::HullComputer {
+                shrink: 0.0,
+                shrink_clamp: 0.0,
+            },
+            polyhedral_features: true,
+        },
+    );
+
+    assert_eq!(shape.convex_hull_points().unwrap().len(), 8);
+
+    let polyhedron = shape.convex_polyhedron().unwrap();
+    assert_eq!(polyhedron.vertices.len(), 8);
+    assert_eq!(polyhedron.faces.len(), 6);
+    assert_eq!(polyhedron.edges.len(), 12);
+    for face in polyhedron.faces.iter() {
+        assert_eq!(face.len(), 4);
+    }
+}
+
+#[test()]
+fn shape_hull() {
+    let shape = Shape::new_convex_hull_with_options(
+        &cube_with_interior_points(),
+        ConvexHullOptions {
+            optimization: HullOptimization::ShapeHull,
+            ..Default::default()
+        },
+    );
+
+    let points = shape.convex_hull_points().unwrap();
+    assert!(points.len() < 125);
+    assert!(shape.convex_polyhedron().is_none());
+}
+
+#[test()]
+fn initialize_polyhedral_features() {
+    let mut shape = Shape::new_convex_hull(&cube_with_interior_points());
+    assert_eq!(shape.convex_hull_points().unwrap().len(), 125);
+    assert!(shape.convex_polyhedron().is_none());
+
+    assert!(shape.initialize_polyhedral_features());
+    let polyhedron = shape.convex_polyhedron().unwrap();
+    assert_eq!(polyhedron.faces.len(), 6);
+    assert_eq!(polyhedron.edges.len(), 12);
+
+    assert!(!Shape::new_sphere(1.0).initialize_polyhedral_features());
+    assert!(Shape::new_sphere(1.0).convex_polyhedron().is_none());
+}
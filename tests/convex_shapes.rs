@@ -0,0 +1,97 @@
+extern crate bulletrs;
+extern crate cgmath;
+
+use std::rc::Rc;
+
+use cgmath::{Vector3, Vector4};
+
+use bulletrs::*;
+
+fn fall_on_plane(shape: Shape) -> f64 {
+    let configuration = CollisionConfiguration::new_default();
+
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    dynamics_world.set_gravity(Vector3::new(0.0, -10.0, 0.0));
+
+    dynamics_world.add_rigid_body(RigidBody::new(
+        0.0,
+        Vector3::new(0.0, 0.0, 0.0),
+        Shape::new_plane(Vector3::new(0.0, 1.0, 0.0), 0.0),
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+
+    let mass = 1.0;
+    let inertia = shape.calculate_local_inertia(mass);
+    assert!(inertia.x > 0.0 && inertia.y > 0.0 && inertia.z > 0.0);
+
+    let mut body = dynamics_world.add_rigid_body(RigidBody::new(
+        mass,
+        inertia,
+        shape,
+        Vector3::new(0.0, 5.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    body.set_angular_factor(Vector3::new(0.0, 0.0, 0.0)).unwrap();
+    body.set_mass(2.0).unwrap();
+
+    for _ in 0..100 {
+        dynamics_world.step_simulation(0.1, 0, 0.0);
+    }
+
+    let (position, _) = body.get_world_position_and_orientation().unwrap();
+    position.y
+}
+
+fn tetrahedron() -> Rc<PointBuffer> {
+    Rc::new(PointBuffer::new(&[
+        Vector3::new(-1.0, -1.0, -1.0),
+        Vector3::new(1.0, -1.0, -1.0),
+        Vector3::new(0.0, -1.0, 1.0),
+        Vector3::new(0.0, 1.0, 0.0),
+    ]))
+}
+
+#[test()]
+fn multi_sphere() {
+    let shape = Shape::new_multi_sphere(&[
+        (Vector3::new(0.0, -0.5, 0.0), 0.5),
+        (Vector3::new(0.0, 0.5, 0.0), 0.25),
+    ]);
+    let y = fall_on_plane(shape);
+    assert!((y - 1.0).abs() < 0.1);
+}
+
+#[test()]
+fn convex_point_cloud() {
+    let points = tetrahedron();
+    let shape = Shape::new_convex_point_cloud(points.clone(), Vector3::new(1.0, 1.0, 1.0));
+    let scaled = Shape::new_convex_point_cloud(points.clone(), Vector3::new(1.0, 2.0, 1.0));
+    assert_eq!(Rc::strong_count(&points), 3);
+    assert_eq!(points.len(), 4);
+    assert_eq!(points.points()[3].y, 1.0);
+
+    let y = fall_on_plane(shape);
+    assert!((y - 1.0).abs() < 0.1);
+    let y = fall_on_plane(scaled);
+    assert!((y - 2.0).abs() < 0.1);
+}
+
+#[test()]
+fn uniform_scaling() {
+    let shape = Shape::new_uniform_scaling(Shape::new_box(Vector3::new(1.0, 1.0, 1.0)), 2.0);
+    let y = fall_on_plane(shape);
+    assert!((y - 2.0).abs() < 0.1);
+}
+
+#[test()]
+#[should_panic]
+fn uniform_scaling_concave() {
+    Shape::new_uniform_scaling(Shape::new_plane(Vector3::new(0.0, 1.0, 0.0), 0.0), 2.0);
+}
@@ -0,0 +1,72 @@
+extern crate bulletrs;
+extern crate cgmath;
+
+use cgmath::{Vector3, Vector4};
+
+use bulletrs::*;
+
+fn fall_on_plane(shape: Shape) -> f64 {
+    let configuration = CollisionConfiguration::new_default();
+
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    dynamics_world.set_gravity(Vector3::new(0.0, -10.0, 0.0));
+
+    let ground_shape = Shape::new_plane(Vector3::new(0.0, 1.0, 0.0), 0.0);
+    dynamics_world.add_rigid_body(RigidBody::new(
+        0.0,
+        Vector3::new(0.0, 0.0, 0.0),
+        ground_shape,
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+
+    let mass = 1.0;
+    let mut body = dynamics_world.add_rigid_body(RigidBody::new(
+        mass,
+        shape.calculate_local_inertia(mass),
+        shape,
+        Vector3::new(0.0, 5.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    body.set_mass(2.0).unwrap();
+
+    for _ in 0..100 {
+        dynamics_world.step_simulation(0.1, 0, 0.0);
+    }
+
+    let (position, _) = body.get_world_position_and_orientation().unwrap();
+    position.y
+}
+
+#[test()]
+fn cylinder() {
+    let shape = Shape::new_cylinder(Vector3::new(0.5, 1.0, 0.5), CapsuleAxis::Y);
+    let inertia = shape.calculate_local_inertia(1.0);
+    assert!(inertia.x > 0.0 && inertia.y > 0.0 && inertia.z > 0.0);
+
+    let y = fall_on_plane(shape);
+    assert!((y - 1.0).abs() < 0.1);
+}
+
+#[test()]
+fn cylinder_axis() {
+    let shape = Shape::new_cylinder(Vector3::new(1.0, 0.5, 0.5), CapsuleAxis::X);
+    let y = fall_on_plane(shape);
+    assert!((y - 0.5).abs() < 0.1);
+}
+
+#[test()]
+fn cone() {
+    let shape = Shape::new_cone(0.5, 2.0, CapsuleAxis::Z);
+    let inertia = shape.calculate_local_inertia(1.0);
+    assert!(inertia.x > 0.0 && inertia.y > 0.0 && inertia.z > 0.0);
+
+    let y = fall_on_plane(shape);
+    assert!(y > 0.0 && y < 1.0);
+}
@@ -0,0 +1,114 @@
+extern crate bulletrs;
+extern crate cgmath;
+
+use cgmath::{Vector3, Vector4};
+
+use bulletrs::*;
+
+#[test()]
+fn central_force() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+    dynamics_world.set_gravity(Vector3::new(0.0, 0.0, 0.0));
+
+    let shape = Shape::new_sphere(1.0);
+    let mut body = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+
+    body.apply_central_force(Vector3::new(10.0, 0.0, 0.0)).unwrap();
+    assert_eq!(body.get_total_force().unwrap().x, 10.0);
+    dynamics_world.step_simulation(0.1, 0, 0.0);
+
+    assert!((body.get_linear_velocity().unwrap().x - 1.0).abs() < 1e-6);
+    assert_eq!(body.get_total_force().unwrap().x, 0.0);
+}
+
+#[test()]
+fn force_at_point_adds_torque() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+    dynamics_world.set_gravity(Vector3::new(0.0, 0.0, 0.0));
+
+    let shape = Shape::new_sphere(1.0);
+    let mut body = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+
+    body.apply_force(Vector3::new(0.0, 10.0, 0.0), Vector3::new(1.0, 0.0, 0.0)).unwrap();
+    assert_eq!(body.get_total_torque().unwrap().z, 10.0);
+    body.clear_forces().unwrap();
+    assert_eq!(body.get_total_force().unwrap().y, 0.0);
+    assert_eq!(body.get_total_torque().unwrap().z, 0.0);
+
+    body.apply_impulse(Vector3::new(0.0, 1.0, 0.0), Vector3::new(1.0, 0.0, 0.0)).unwrap();
+    assert!(body.get_linear_velocity().unwrap().y > 0.0);
+    assert!(body.get_angular_velocity().unwrap().z > 0.0);
+
+    body.apply_torque_impulse(Vector3::new(0.0, 0.0, -100.0)).unwrap();
+    assert!(body.get_angular_velocity().unwrap().z < 0.0);
+}
+
+#[test()]
+fn forces_wake_sleeping_bodies() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+    dynamics_world.set_gravity(Vector3::new(0.0, 0.0, 0.0));
+
+    let shape = Shape::new_sphere(1.0);
+    let mut body = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+
+    body.set_activation_state(ActivationState::IslandSleeping).unwrap();
+    body.apply_torque(Vector3::new(0.0, 1.0, 0.0)).unwrap();
+    dynamics_world.step_simulation(0.1, 0, 0.0);
+    assert!(body.get_angular_velocity().unwrap().y > 0.0);
+
+    let shape = Shape::new_sphere(1.0);
+    let mut body = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(10.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    body.set_user_index(1).unwrap();
+    body.set_wake_on_force(false).unwrap();
+    body.set_activation_state(ActivationState::IslandSleeping).unwrap();
+
+    // Setting is kept by the body, not by the handle
+    let mut other_handle = dynamics_world.body_by_user_index(1).unwrap();
+    assert_eq!(other_handle.get_wake_on_force(), Ok(false));
+    other_handle.apply_central_impulse(Vector3::new(1.0, 0.0, 0.0)).unwrap();
+    dynamics_world.step_simulation(0.1, 0, 0.0);
+    let (position, _) = body.get_world_position_and_orientation().unwrap();
+    assert_eq!(position.x, 10.0);
+}
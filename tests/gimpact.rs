@@ -0,0 +1,83 @@
+extern crate bulletrs;
+extern crate cgmath;
+
+use cgmath::{Vector3, Vector4};
+
+use bulletrs::*;
+
+fn cube() -> Shape {
+    Shape::new_gimpact_mesh(
+        &[
+            Vector3::new(-1.0, -1.0, -1.0),
+            Vector3::new(1.0, -1.0, -1.0),
+            Vector3::new(1.0, 1.0, -1.0),
+            Vector3::new(-1.0, 1.0, -1.0),
+            Vector3::new(-1.0, -1.0, 1.0),
+            Vector3::new(1.0, -1.0, 1.0),
+            Vector3::new(1.0, 1.0, 1.0),
+            Vector3::new(-1.0, 1.0, 1.0),
+        ],
+        &[
+            0, 2, 1, 0, 3, 2, // back
+            4, 5, 6, 4, 6, 7, // front
+            0, 1, 5, 0, 5, 4, // bottom
+            3, 6, 2, 3, 7, 6, // top
+            0, 4, 7, 0, 7, 3, // left
+            1, 2, 6, 1, 6, 5, // right
+        ],
+    )
+}
+
+#[test()]
+fn gimpact_mesh() {
+    let configuration = CollisionConfiguration::new_default();
+
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    dynamics_world.set_gravity(Vector3::new(0.0, -10.0, 0.0));
+
+    dynamics_world.add_rigid_body(RigidBody::new(
+        0.0,
+        Vector3::new(0.0, 0.0, 0.0),
+        Shape::new_box(Vector3::new(10.0, 1.0, 10.0)),
+        Vector3::new(0.0, -1.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+
+    let shape = cube();
+    let mass = 1.0;
+    let inertia = shape.calculate_local_inertia(mass);
+    assert!(inertia.x > 0.0 && inertia.y > 0.0 && inertia.z > 0.0);
+
+    let lower = dynamics_world.add_rigid_body(RigidBody::new(
+        mass,
+        inertia,
+        shape,
+        Vector3::new(0.0, 3.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+
+    let shape = cube();
+    let upper = dynamics_world.add_rigid_body(RigidBody::new(
+        mass,
+        shape.calculate_local_inertia(mass),
+        shape,
+        Vector3::new(0.0, 7.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+
+    for _ in 0..300 {
+        dynamics_world.step_simulation(1.0 / 60.0, 0, 0.0);
+    }
+
+    let (position, _) = lower.get_world_position_and_orientation().unwrap();
+    assert!((position.y - 1.0).abs() < 0.2);
+
+    let (position, _) = upper.get_world_position_and_orientation().unwrap();
+    assert!((position.y - 3.0).abs() < 0.3);
+}
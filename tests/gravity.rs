@@ -37,15 +37,15 @@ fn set_gravity() {
         Vector3::new(0.0, 2.0, 0.0),
         Vector4::new(0.0, 0.0, 0.0, 1.0),
     ));
-    body2.set_gravity(Vector3::new(0.0, 0.0, 0.0));
+    body2.set_gravity(Vector3::new(0.0, 0.0, 0.0)).unwrap();
 
     for _ in 0 .. 10 {
         dynamics_world.step_simulation(0.1, 0, 0.0);
     }
 
-    let (position, _) = body1.get_world_position_and_orientation();
+    let (position, _) = body1.get_world_position_and_orientation().unwrap();
     assert!(position.y != 2.0);
 
-    let (position, _) = body2.get_world_position_and_orientation();
+    let (position, _) = body2.get_world_position_and_orientation().unwrap();
     assert_eq!(position.y, 2.0);
 }
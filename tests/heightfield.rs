@@ -0,0 +1,77 @@
+extern crate bulletrs;
+extern crate cgmath;
+
+use cgmath::{Vector3, Vector4};
+
+use bulletrs::*;
+
+#[test()]
+fn heightfield() {
+    let configuration = CollisionConfiguration::new_default();
+
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    dynamics_world.set_gravity(Vector3::new(0.0, -10.0, 0.0));
+
+    let heights = vec![0.0f32; 16];
+    let shape = Shape::new_heightfield(4, 4, &heights, 0.0, 2.0, CapsuleAxis::Y, false);
+    let heightfield = shape.heightfield().unwrap().clone();
+
+    dynamics_world.add_rigid_body(RigidBody::new(
+        0.0,
+        Vector3::new(0.0, 0.0, 0.0),
+        shape,
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+
+    let shape = Shape::new_sphere(0.5);
+    let mass = 1.0;
+    let body = dynamics_world.add_rigid_body(RigidBody::new(
+        mass,
+        shape.calculate_local_inertia(mass),
+        shape,
+        Vector3::new(0.0, 5.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+
+    for _ in 0..100 {
+        dynamics_world.step_simulation(0.1, 0, 0.0);
+    }
+
+    // heightfield is centered between min and max height
+    let (position, _) = body.get_world_position_and_orientation().unwrap();
+    assert!((position.y + 0.5).abs() < 0.1);
+
+    let result = dynamics_world.raytest(ClosestRayResultCallback::new(
+        Vector3::new(1.2, 10.0, 0.7),
+        Vector3::new(1.2, -10.0, 0.7),
+    ));
+    let hit = &result.intersections()[0];
+    assert!((hit.point.y + 1.0).abs() < 1e-6);
+    assert_eq!(hit.shape_part, 2);
+    assert_eq!(hit.triangle_index, 2);
+
+    for x in 0..heightfield.width() {
+        for y in 0..heightfield.length() {
+            heightfield.set_height(x, y, 1.5);
+        }
+    }
+    assert_eq!(heightfield.height(3, 3), 1.5);
+
+    let result = dynamics_world.raytest(AllRayResultCallback::new(
+        Vector3::new(1.2, 10.0, 0.7),
+        Vector3::new(1.2, -10.0, 0.7),
+    ));
+    let intersections = result.intersections();
+    let hit = intersections
+        .iter()
+        .find(|hit| hit.triangle_index != -1)
+        .unwrap();
+    assert!((hit.point.y - 0.5).abs() < 1e-6);
+}
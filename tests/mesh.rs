@@ -1,7 +1,7 @@
 extern crate bulletrs;
 extern crate cgmath;
 
-use cgmath::{Vector3, Vector4};
+use cgmath::{Quaternion, Rotation, Vector3, Vector4};
 
 use bulletrs::*;
 
@@ -62,3 +62,76 @@ fn new_convex_hull() {
         Vector3::new(0.0, 0.0, 1.0),
     ]);
 }
+
+#[test()]
+fn compound_center_of_mass() {
+    let configuration = CollisionConfiguration::new_default();
+
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+    dynamics_world.set_gravity(Vector3::new(0.0, 0.0, 0.0));
+
+    let (shape, (center, _)) = Shape::new_compound_with_masses(vec![
+        (
+            Shape::new_sphere(1.0),
+            3.0,
+            Vector3::new(-2.0, 0.0, 0.0),
+            Vector4::new(0.0, 0.0, 0.0, 1.0),
+        ),
+        (
+            Shape::new_sphere(1.0),
+            1.0,
+            Vector3::new(2.0, 0.0, 0.0),
+            Vector4::new(0.0, 0.0, 0.0, 1.0),
+        ),
+    ]);
+    assert!((center.x + 1.0).abs() < 1e-6);
+    assert!(center.y.abs() < 1e-6 && center.z.abs() < 1e-6);
+
+    // Spheres of mass 3 and 1 at distances 1 and 3 from the center of mass
+    let mass = 4.0;
+    let inertia = shape.calculate_local_inertia(mass);
+    let mut moments = [inertia.x, inertia.y, inertia.z];
+    moments.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert!((moments[0] - 0.4 * 4.0).abs() < 1e-6);
+    assert!((moments[1] - (0.4 * 4.0 + 3.0 + 9.0)).abs() < 1e-6);
+    assert!((moments[2] - (0.4 * 4.0 + 3.0 + 9.0)).abs() < 1e-6);
+
+    let mut body = dynamics_world.add_rigid_body(RigidBody::new(
+        mass,
+        inertia,
+        shape,
+        Vector3::new(4.0, 2.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+
+    let (position, _) = body.get_world_position_and_orientation().unwrap();
+    assert!((position.x - 4.0).abs() < 1e-6);
+    assert!((position.y - 2.0).abs() < 1e-6);
+
+    body.reset_position_and_orientation(
+        Vector3::new(1.0, 1.0, 1.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ).unwrap();
+    dynamics_world.step_simulation(0.1, 0, 0.0);
+    let (position, _) = body.get_world_position_and_orientation().unwrap();
+    assert!((position.x - 1.0).abs() < 1e-6);
+    assert!((position.y - 1.0).abs() < 1e-6);
+
+    // Spinning body keeps its center of mass in place, not its origin
+    body.reset_angular_velocity(Vector3::new(0.0, 0.0, 1.0)).unwrap();
+    for _ in 0..10 {
+        dynamics_world.step_simulation(0.1, 0, 0.0);
+    }
+    let (position, orientation) = body.get_world_position_and_orientation().unwrap();
+    let position: Vector3<f64> = position.into();
+    let orientation = Quaternion::new(orientation.w, orientation.x, orientation.y, orientation.z);
+    let center = position + orientation.rotate_vector(Vector3::new(-1.0, 0.0, 0.0));
+    assert!(center.x.abs() < 1e-3);
+    assert!((center.y - 1.0).abs() < 1e-3);
+    assert!((position.x - 1.0).abs() > 0.1);
+}
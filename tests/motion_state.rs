@@ -0,0 +1,95 @@
+extern crate bulletrs;
+extern crate cgmath;
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use cgmath::{Vector3, Vector4};
+
+use bulletrs::*;
+
+struct SceneNode {
+    position: Rc<Cell<[f64; 3]>>,
+    updates: Rc<Cell<usize>>,
+}
+
+impl MotionState for SceneNode {
+    fn get_world_transform(&self) -> (bulletrs::Vector3<f64>, bulletrs::Vector4<f64>) {
+        let position = self.position.get();
+        (
+            Vector3::new(position[0], position[1], position[2]).into(),
+            Vector4::new(0.0, 0.0, 0.0, 1.0).into(),
+        )
+    }
+
+    fn set_world_transform(
+        &mut self,
+        position: bulletrs::Vector3<f64>,
+        _orientation: bulletrs::Vector4<f64>,
+    ) {
+        self.position.set([position.x, position.y, position.z]);
+        self.updates.set(self.updates.get() + 1);
+    }
+}
+
+#[test()]
+fn motion_state_receives_transforms() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+    dynamics_world.set_gravity(Vector3::new(0.0, -10.0, 0.0));
+
+    let position = Rc::new(Cell::new([0.0, 20.0, 0.0]));
+    let updates = Rc::new(Cell::new(0));
+    let shape = Shape::new_sphere(1.0);
+    let mut body = RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    );
+    body.set_motion_state(SceneNode {
+        position: position.clone(),
+        updates: updates.clone(),
+    });
+    let mut falling = dynamics_world.add_rigid_body(body);
+
+    let (start, _) = falling.get_world_position_and_orientation().unwrap();
+    assert_eq!(start.y, 20.0);
+
+    let shape = Shape::new_sphere(1.0);
+    let sleeping_updates = Rc::new(Cell::new(0));
+    let mut body = RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    );
+    body.set_motion_state(SceneNode {
+        position: Rc::new(Cell::new([10.0, 0.0, 0.0])),
+        updates: sleeping_updates.clone(),
+    });
+    let mut sleeping = dynamics_world.add_rigid_body(body);
+    sleeping.set_activation_state(ActivationState::IslandSleeping).unwrap();
+    let (start, _) = sleeping.get_world_position_and_orientation().unwrap();
+    assert_eq!(start.x, 10.0);
+    falling.set_activation_state(ActivationState::DisableDeactivation).unwrap();
+
+    for _ in 0..10 {
+        dynamics_world.step_simulation(0.1, 0, 0.0);
+    }
+
+    assert_eq!(updates.get(), 10);
+    assert!(position.get()[1] < 20.0);
+    let (current, _) = falling.get_world_position_and_orientation().unwrap();
+    assert_eq!(current.y, position.get()[1]);
+    assert_eq!(sleeping_updates.get(), 0);
+    let (current, _) = sleeping.get_world_position_and_orientation().unwrap();
+    assert_eq!(current.x, 10.0);
+}
@@ -0,0 +1,110 @@
+extern crate bulletrs;
+extern crate cgmath;
+
+use cgmath::{Vector3, Vector4};
+use std::cell::Cell;
+use std::rc::Rc;
+
+use bulletrs::*;
+
+#[test()]
+fn same_team_pass_through() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    dynamics_world.add_rigid_body(RigidBody::new(
+        0.0,
+        Vector3::new(0.0, 0.0, 0.0),
+        Shape::new_plane(Vector3::new(0.0, 1.0, 0.0), 0.0),
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    let shape = Shape::new_sphere(1.0);
+    let mut lower = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(0.0, 1.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    lower.set_user_index(1).unwrap();
+    let shape = Shape::new_sphere(1.0);
+    let mut upper = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(0.0, 4.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    upper.set_user_index(1).unwrap();
+
+    let calls = Rc::new(Cell::new(0));
+    let filter_calls = calls.clone();
+    dynamics_world.set_overlap_filter(move |body0, body1| {
+        filter_calls.set(filter_calls.get() + 1);
+        let team0 = body0.get_user_index().unwrap();
+        let team1 = body1.get_user_index().unwrap();
+        team0 == -1 || team1 == -1 || team0 != team1
+    });
+
+    for _ in 0..120 {
+        dynamics_world.step_simulation(1.0 / 60.0, 0, 1.0 / 60.0);
+    }
+
+    assert!(calls.get() > 0);
+    // both spheres rest on the ground, upper one went through the lower one
+    assert!((lower.get_world_position_and_orientation().unwrap().0.y - 1.0).abs() < 0.1);
+    assert!((upper.get_world_position_and_orientation().unwrap().0.y - 1.0).abs() < 0.1);
+}
+
+#[test()]
+fn clear_filter() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    dynamics_world.add_rigid_body(RigidBody::new(
+        0.0,
+        Vector3::new(0.0, 0.0, 0.0),
+        Shape::new_plane(Vector3::new(0.0, 1.0, 0.0), 0.0),
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    let shape = Shape::new_sphere(1.0);
+    let mut lower = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(0.0, 1.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    lower.set_user_index(1).unwrap();
+    let shape = Shape::new_sphere(1.0);
+    let mut upper = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(0.0, 4.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    upper.set_user_index(1).unwrap();
+
+    dynamics_world.set_overlap_filter(|_, _| false);
+    dynamics_world.clear_overlap_filter();
+
+    for _ in 0..120 {
+        dynamics_world.step_simulation(1.0 / 60.0, 0, 1.0 / 60.0);
+    }
+
+    assert!((lower.get_world_position_and_orientation().unwrap().0.y - 1.0).abs() < 0.1);
+    assert!((upper.get_world_position_and_orientation().unwrap().0.y - 3.0).abs() < 0.1);
+}
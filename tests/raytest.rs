@@ -59,9 +59,11 @@ fn ray_test() {
     assert_eq!(result.intersections().len(), 2);
 
     for result in result.intersections() {
-        assert_eq!(result.rigidbody().as_ref().unwrap().removed(), false);
-        dynamics_world.remove_body(result.rigidbody().as_ref().unwrap());
-        assert_eq!(result.rigidbody().as_ref().unwrap().removed(), true);
+        let body = result.rigidbody().unwrap();
+        assert_eq!(body.removed(), false);
+        dynamics_world.remove_body(&body).unwrap();
+        assert_eq!(body.removed(), true);
+        assert!(result.rigidbody().is_none());
     }
 }
 
@@ -0,0 +1,127 @@
+extern crate bulletrs;
+extern crate cgmath;
+
+use cgmath::{Vector3, Vector4};
+
+use bulletrs::*;
+
+#[test()]
+fn remove_and_add_again() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    let shape = Shape::new_sphere(1.0);
+    let mut body = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    body.set_user_index(7).unwrap();
+    body.set_user_data(5).unwrap();
+
+    let removed = dynamics_world.remove_body(&body).unwrap();
+    assert!(body.removed());
+
+    let body = dynamics_world.add_rigid_body(removed);
+    assert_eq!(body.get_user_index(), Ok(7));
+    assert_eq!(*body.get_user_data::<i32>().unwrap().unwrap(), 5);
+
+    dynamics_world.step_simulation(0.1, 0, 0.1);
+    let (position, _) = body.get_world_position_and_orientation().unwrap();
+    assert!(position.y < 0.0);
+}
+
+#[test()]
+fn slot_reused() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    let shape = Shape::new_sphere(1.0);
+    let body = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    dynamics_world.remove_body(&body).unwrap();
+
+    let shape = Shape::new_sphere(1.0);
+    let other = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(10.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    assert!(body.removed());
+    assert!(!other.removed());
+    assert_eq!(body.get_mass().err(), Some(Error::BodyDeleted));
+
+    let result = dynamics_world.raytest(ClosestRayResultCallback::new(
+        Vector3::new(5.0, 0.0, 0.0),
+        Vector3::new(15.0, 0.0, 0.0),
+    ));
+    let hit = result.intersections()[0].rigidbody().unwrap();
+    assert_eq!(
+        hit.get_world_position_and_orientation().unwrap(),
+        other.get_world_position_and_orientation().unwrap()
+    );
+}
+
+#[test()]
+fn attached_constraints_removed() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    let shape = Shape::new_sphere(1.0);
+    let body_a = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    let shape = Shape::new_sphere(1.0);
+    let body_b = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(3.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+
+    let hinge = HingeConstraint::new(
+        &body_a,
+        &body_b,
+        Vector3::new(1.5, 0.0, 0.0),
+        Vector3::new(-1.5, 0.0, 0.0),
+        Vector3::new(0.0, 0.0, 1.0),
+        Vector3::new(0.0, 0.0, 1.0),
+        false,
+    ).unwrap();
+    dynamics_world.add_constraint(hinge, true).unwrap();
+
+    drop(dynamics_world.remove_body(&body_b).unwrap());
+    for _ in 0..10 {
+        dynamics_world.step_simulation(0.1, 0, 0.1);
+    }
+    assert!(!body_a.removed());
+}
@@ -20,7 +20,7 @@ fn reset_linear_velocity() {
         shape,
         Vector3::new(0.0, 0.0, 0.0),
         Vector4::new(0.0, 0.0, 0.0, 1.0)));
-    rb.reset_linear_velocity(Vector3::new(1.0, 2.0, 3.0));
-    let v: Vector3<f64> = rb.get_linear_velocity().into();
+    rb.reset_linear_velocity(Vector3::new(1.0, 2.0, 3.0)).unwrap();
+    let v: Vector3<f64> = rb.get_linear_velocity().unwrap().into();
     assert_eq!(v, Vector3::new(1.0,2.0,3.0));
 }
\ No newline at end of file
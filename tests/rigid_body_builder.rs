@@ -0,0 +1,100 @@
+extern crate bulletrs;
+extern crate cgmath;
+
+use cgmath::{Vector3, Vector4};
+
+use bulletrs::*;
+
+#[test()]
+fn builder_fields() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    let body = dynamics_world.add_rigid_body(
+        RigidBodyBuilder::new(Shape::new_sphere(1.0))
+            .mass(3.0)
+            .position(Vector3::new(1.0, 2.0, 3.0))
+            .orientation(Vector4::new(0.0, 0.0, 0.0, 1.0))
+            .friction(0.3)
+            .rolling_friction(0.1)
+            .spinning_friction(0.2)
+            .restitution(0.4)
+            .linear_damping(0.25)
+            .angular_damping(0.75)
+            .build(),
+    );
+
+    assert_eq!(body.get_mass().unwrap(), 3.0);
+    assert_eq!(body.get_friction().unwrap(), 0.3);
+    assert_eq!(body.get_rolling_friction().unwrap(), 0.1);
+    assert_eq!(body.get_spinning_friction().unwrap(), 0.2);
+    assert_eq!(body.get_restitution().unwrap(), 0.4);
+    assert_eq!(body.get_linear_damping().unwrap(), 0.25);
+    assert_eq!(body.get_angular_damping().unwrap(), 0.75);
+
+    let (position, _) = body.get_world_position_and_orientation().unwrap();
+    assert_eq!((position.x, position.y, position.z), (1.0, 2.0, 3.0));
+}
+
+#[test()]
+fn inertia_calculated_from_shape() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    let shape = Shape::new_box(Vector3::new(1.0, 2.0, 3.0));
+    let expected = shape.calculate_local_inertia(2.0);
+    let body = dynamics_world.add_rigid_body(RigidBodyBuilder::new(shape).mass(2.0).build());
+
+    let inertia = body.get_local_inertia().unwrap();
+    assert!((inertia.x - expected.x).abs() < 1e-9);
+    assert!((inertia.y - expected.y).abs() < 1e-9);
+    assert!((inertia.z - expected.z).abs() < 1e-9);
+    assert!(inertia.x > 0.0);
+}
+
+#[test()]
+fn explicit_inertia() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    let body = dynamics_world.add_rigid_body(
+        RigidBodyBuilder::new(Shape::new_sphere(1.0))
+            .mass(1.0)
+            .inertia(Vector3::new(5.0, 6.0, 7.0))
+            .build(),
+    );
+
+    let inertia = body.get_local_inertia().unwrap();
+    assert_eq!((inertia.x, inertia.y, inertia.z), (5.0, 6.0, 7.0));
+}
+
+#[test()]
+fn default_is_static() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    let body = dynamics_world.add_rigid_body(RigidBodyBuilder::new(Shape::new_sphere(1.0)).build());
+
+    assert_eq!(body.get_mass().unwrap(), 0.0);
+    assert_eq!(body.get_body_type().unwrap(), BodyType::Static);
+}
@@ -0,0 +1,112 @@
+extern crate bulletrs;
+extern crate cgmath;
+
+use cgmath::{Vector3, Vector4};
+
+use bulletrs::*;
+
+#[test()]
+fn getters_and_setters() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    let shape = Shape::new_box(Vector3::new(1.0, 1.0, 1.0));
+    let mut body = dynamics_world.add_rigid_body(RigidBody::new(
+        2.0,
+        shape.calculate_local_inertia(2.0),
+        shape,
+        Vector3::new(0.0, 10.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+
+    assert_eq!(body.get_mass().unwrap(), 2.0);
+    assert_eq!(body.get_inverse_mass().unwrap(), 0.5);
+
+    body.set_friction(0.3).unwrap();
+    assert_eq!(body.get_friction().unwrap(), 0.3);
+    body.set_restitution(0.4).unwrap();
+    assert_eq!(body.get_restitution().unwrap(), 0.4);
+    body.set_rolling_friction(0.1).unwrap();
+    assert_eq!(body.get_rolling_friction().unwrap(), 0.1);
+    body.set_spinning_friction(0.2).unwrap();
+    assert_eq!(body.get_spinning_friction().unwrap(), 0.2);
+
+    body.set_damping(0.5, 0.6).unwrap();
+    assert_eq!(body.get_linear_damping().unwrap(), 0.5);
+    assert_eq!(body.get_angular_damping().unwrap(), 0.6);
+
+    body.set_linear_factor(Vector3::new(1.0, 0.0, 1.0)).unwrap();
+    assert_eq!(body.get_linear_factor().unwrap().y, 0.0);
+    body.set_angular_factor(Vector3::new(0.0, 1.0, 0.0)).unwrap();
+    assert_eq!(body.get_angular_factor().unwrap().x, 0.0);
+
+    body.reset_angular_velocity(Vector3::new(0.0, 3.0, 0.0)).unwrap();
+    assert_eq!(body.get_angular_velocity().unwrap().y, 3.0);
+
+    body.set_local_inertia(Vector3::new(1.0, 2.0, 4.0)).unwrap();
+    assert_eq!(body.get_local_inertia().unwrap().z, 4.0);
+    assert_eq!(body.get_mass().unwrap(), 2.0);
+    let tensor = body.get_inverse_inertia_tensor_world().unwrap();
+    assert!((tensor.z.z - 0.25).abs() < 1e-9);
+
+    let (min, max) = body.get_aabb().unwrap();
+    assert!(min.y < 9.0 && max.y > 11.0);
+}
+
+#[test()]
+fn linear_factor_locks_axis() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    dynamics_world.set_gravity(Vector3::new(0.0, -10.0, 0.0));
+    let shape = Shape::new_sphere(1.0);
+    let mut body = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(0.0, 10.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    body.set_linear_factor(Vector3::new(1.0, 0.0, 1.0)).unwrap();
+
+    for _ in 0..10 {
+        dynamics_world.step_simulation(0.1, 0, 0.0);
+    }
+    let (position, _) = body.get_world_position_and_orientation().unwrap();
+    assert_eq!(position.y, 10.0);
+}
+
+#[test()]
+fn set_mass_of_sphere() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    let shape = Shape::new_sphere(1.0);
+    let mut body = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+
+    body.set_mass(5.0).unwrap();
+    assert_eq!(body.get_mass().unwrap(), 5.0);
+    let inertia = body.get_local_inertia().unwrap();
+    assert!((inertia.x - 0.4 * 5.0).abs() < 1e-9);
+}
@@ -0,0 +1,94 @@
+extern crate bulletrs;
+extern crate cgmath;
+
+use cgmath::{Vector3, Vector4};
+
+use bulletrs::*;
+
+#[test()]
+fn shape_type() {
+    assert_eq!(Shape::new_sphere(1.0).shape_type(), ShapeType::Sphere);
+    assert_eq!(
+        Shape::new_box(Vector3::new(1.0, 1.0, 1.0)).shape_type(),
+        ShapeType::Box
+    );
+    assert_eq!(
+        Shape::new_cylinder(Vector3::new(1.0, 1.0, 1.0), CapsuleAxis::Z).shape_type(),
+        ShapeType::Cylinder
+    );
+    assert_eq!(
+        Shape::new_plane(Vector3::new(0.0, 1.0, 0.0), 0.0).shape_type(),
+        ShapeType::StaticPlane
+    );
+}
+
+#[test()]
+fn aabb() {
+    let mut shape = Shape::new_box(Vector3::new(1.0, 2.0, 3.0));
+    shape.set_margin(0.0);
+    assert_eq!(shape.margin(), 0.0);
+
+    let (min, max) = shape.aabb(Vector3::new(1.0, 0.0, 0.0), Vector4::new(0.0, 0.0, 0.0, 1.0));
+    let min: Vector3<f64> = min.into();
+    let max: Vector3<f64> = max.into();
+    assert!((min - Vector3::new(0.0, -2.0, -3.0)).x.abs() < 1e-6);
+    assert!((max - Vector3::new(2.0, 2.0, 3.0)).z.abs() < 1e-6);
+
+    let (center, radius) = shape.bounding_sphere();
+    assert_eq!(center.x, 0.0);
+    assert!((radius - 14.0f64.sqrt()).abs() < 1e-6);
+}
+
+#[test()]
+fn local_scaling() {
+    let mut shape = Shape::new_sphere(1.0);
+    let scaling: Vector3<f64> = shape.local_scaling().into();
+    assert_eq!(scaling, Vector3::new(1.0, 1.0, 1.0));
+
+    shape.set_local_scaling(Vector3::new(2.0, 2.0, 2.0));
+    let scaling: Vector3<f64> = shape.local_scaling().into();
+    assert_eq!(scaling, Vector3::new(2.0, 2.0, 2.0));
+
+    let (_, radius) = shape.bounding_sphere();
+    assert!(radius >= 2.0);
+}
+
+#[test()]
+fn scale_body_shape() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    let shape = Shape::new_box(Vector3::new(0.5, 0.5, 0.5));
+    let mut body = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    let inertia = body.get_local_inertia().unwrap();
+
+    body.update_shape(|shape| shape.set_local_scaling(Vector3::new(2.0, 2.0, 2.0)))
+        .unwrap();
+    let scaled_inertia = body.get_local_inertia().unwrap();
+    assert!(scaled_inertia.x > inertia.x * 3.0);
+    assert_eq!(body.get_mass(), Ok(1.0));
+
+    let shared = SharedShape::new(Shape::new_box(Vector3::new(0.5, 0.5, 0.5)));
+    let mut body = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shared.calculate_local_inertia(1.0),
+        shared.clone(),
+        Vector3::new(5.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    assert_eq!(
+        body.update_shape(|shape| shape.set_margin(0.0)),
+        Err(Error::ShapeInUse)
+    );
+}
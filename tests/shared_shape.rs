@@ -0,0 +1,89 @@
+extern crate bulletrs;
+extern crate cgmath;
+
+use cgmath::{Vector3, Vector4};
+
+use bulletrs::*;
+
+#[test()]
+fn shared_shape() {
+    let configuration = CollisionConfiguration::new_default();
+
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    dynamics_world.set_gravity(Vector3::new(0.0, -10.0, 0.0));
+
+    dynamics_world.add_rigid_body(RigidBody::new(
+        0.0,
+        Vector3::new(0.0, 0.0, 0.0),
+        Shape::new_plane(Vector3::new(0.0, 1.0, 0.0), 0.0),
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+
+    let crate_shape = SharedShape::new(Shape::new_box(Vector3::new(0.5, 0.5, 0.5)));
+    let mass = 1.0;
+    let inertia = crate_shape.calculate_local_inertia(mass);
+
+    let crates = (0..10)
+        .map(|i| {
+            dynamics_world.add_rigid_body(RigidBody::new(
+                mass,
+                inertia,
+                crate_shape.clone(),
+                Vector3::new(i as f64 * 2.0, 3.0, 0.0),
+                Vector4::new(0.0, 0.0, 0.0, 1.0),
+            ))
+        })
+        .collect::<Vec<_>>();
+
+    let compound = Shape::new_compound(vec![
+        (
+            crate_shape.clone(),
+            Vector3::new(-1.0, 0.0, 0.0),
+            Vector4::new(0.0, 0.0, 0.0, 1.0),
+        ),
+        (
+            crate_shape.clone(),
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector4::new(0.0, 0.0, 0.0, 1.0),
+        ),
+    ]);
+    assert_eq!(SharedShape::strong_count(&crate_shape), 13);
+
+    let pair = dynamics_world.add_rigid_body(RigidBody::new(
+        mass,
+        compound.calculate_local_inertia(mass),
+        compound,
+        Vector3::new(0.0, 3.0, 5.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+
+    drop(crate_shape);
+
+    for _ in 0..100 {
+        dynamics_world.step_simulation(0.1, 0, 0.0);
+    }
+
+    for body in crates.iter().chain(Some(&pair)) {
+        let (position, _) = body.get_world_position_and_orientation().unwrap();
+        assert!((position.y - 0.5).abs() < 0.1);
+    }
+}
+
+#[test()]
+fn get_mut_only_when_unique() {
+    let mut shape = SharedShape::new(Shape::new_sphere(1.0));
+    assert!(SharedShape::get_mut(&mut shape).is_some());
+
+    let other = shape.clone();
+    assert!(SharedShape::get_mut(&mut shape).is_none());
+
+    drop(other);
+    assert!(SharedShape::get_mut(&mut shape).is_some());
+}
@@ -0,0 +1,251 @@
+extern crate bulletrs;
+extern crate cgmath;
+
+use cgmath::{Vector3, Vector4};
+
+use bulletrs::*;
+
+#[test()]
+fn removed_body_handle() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    let shape = Shape::new_sphere(1.0);
+    let mut body = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    let shape = Shape::new_sphere(1.0);
+    let other = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(10.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    let body_clone = body.clone();
+
+    assert_eq!(body.get_mass(), Ok(1.0));
+    dynamics_world.remove_body(&body).unwrap();
+
+    assert!(body.removed());
+    assert!(body_clone.removed());
+    assert_eq!(body.get_mass(), Err(Error::BodyDeleted));
+    assert_eq!(body.set_friction(0.5), Err(Error::BodyDeleted));
+    assert_eq!(
+        body_clone.get_world_position_and_orientation().err(),
+        Some(Error::BodyDeleted)
+    );
+    assert_eq!(dynamics_world.remove_body(&body).err(), Some(Error::BodyDeleted));
+
+    assert!(!other.removed());
+    assert_eq!(other.get_mass(), Ok(1.0));
+    dynamics_world.step_simulation(0.1, 0, 0.1);
+}
+
+#[test()]
+fn dropped_world_handle() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    let shape = Shape::new_sphere(1.0);
+    let body = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    drop(dynamics_world);
+
+    assert!(body.removed());
+    assert_eq!(body.get_linear_velocity().err(), Some(Error::BodyDeleted));
+}
+
+#[test()]
+fn constraint_with_removed_body() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    let shape = Shape::new_sphere(1.0);
+    let body_a = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    let shape = Shape::new_sphere(1.0);
+    let body_b = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(10.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    dynamics_world.remove_body(&body_b).unwrap();
+
+    let hinge = HingeConstraint::new(
+        &body_a,
+        &body_b,
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(1.0, 0.0, 0.0),
+        false,
+    );
+    assert!(hinge.is_err());
+}
+
+#[test()]
+fn body_removed_before_adding_constraint() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    let shape = Shape::new_sphere(1.0);
+    let body_a = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    let shape = Shape::new_sphere(1.0);
+    let body_b = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(3.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+
+    let hinge = HingeConstraint::new(
+        &body_a,
+        &body_b,
+        Vector3::new(1.5, 0.0, 0.0),
+        Vector3::new(-1.5, 0.0, 0.0),
+        Vector3::new(0.0, 0.0, 1.0),
+        Vector3::new(0.0, 0.0, 1.0),
+        false,
+    ).unwrap();
+    drop(dynamics_world.remove_body(&body_b).unwrap());
+
+    assert_eq!(dynamics_world.add_constraint(hinge, true), Err(Error::BodyDeleted));
+    dynamics_world.step_simulation(0.1, 0, 0.1);
+}
+
+#[test()]
+fn constraint_from_other_world() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    let configuration = CollisionConfiguration::new_default();
+    let mut other_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    let shape = Shape::new_sphere(1.0);
+    let body_a = other_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    let shape = Shape::new_sphere(1.0);
+    let body_b = other_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(3.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    let shape = Shape::new_sphere(1.0);
+    dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    let shape = Shape::new_sphere(1.0);
+    dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(3.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+
+    let hinge = HingeConstraint::new(
+        &body_a,
+        &body_b,
+        Vector3::new(1.5, 0.0, 0.0),
+        Vector3::new(-1.5, 0.0, 0.0),
+        Vector3::new(0.0, 0.0, 1.0),
+        Vector3::new(0.0, 0.0, 1.0),
+        false,
+    ).unwrap();
+    assert_eq!(dynamics_world.add_constraint(hinge, true), Err(Error::NoValue));
+}
+
+#[test()]
+fn not_removed_while_bodies_borrowed() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    let shape = Shape::new_sphere(1.0);
+    let body = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+
+    let mut bodies = dynamics_world.bodies_mut();
+    assert!(bodies.next_body().is_some());
+    assert_eq!(body.get_mass(), Err(Error::BodiesBorrowed));
+    assert!(!body.removed());
+    drop(bodies);
+
+    assert!(!body.removed());
+    dynamics_world.remove_body(&body).unwrap();
+    assert!(body.removed());
+}
@@ -0,0 +1,75 @@
+extern crate bulletrs;
+extern crate cgmath;
+
+use cgmath::{Vector3, Vector4};
+
+use bulletrs::*;
+
+fn quad() -> Shape {
+    Shape::new_triangle_mesh(
+        &[
+            Vector3::new(-10.0, 0.0, -10.0),
+            Vector3::new(10.0, 0.0, -10.0),
+            Vector3::new(10.0, 0.0, 10.0),
+            Vector3::new(-10.0, 0.0, 10.0),
+        ],
+        &[0, 1, 2, 0, 2, 3],
+    )
+}
+
+fn drop_sphere(ground: Shape, x: f64) -> f64 {
+    let configuration = CollisionConfiguration::new_default();
+
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    dynamics_world.set_gravity(Vector3::new(0.0, -10.0, 0.0));
+
+    dynamics_world.add_rigid_body(RigidBody::new(
+        0.0,
+        Vector3::new(0.0, 0.0, 0.0),
+        ground,
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+
+    let shape = Shape::new_sphere(1.0);
+    let mass = 1.0;
+    let body = dynamics_world.add_rigid_body(RigidBody::new(
+        mass,
+        shape.calculate_local_inertia(mass),
+        shape,
+        Vector3::new(x, 5.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+
+    for _ in 0..100 {
+        dynamics_world.step_simulation(0.1, 0, 0.0);
+    }
+
+    let (position, _) = body.get_world_position_and_orientation().unwrap();
+    position.y
+}
+
+#[test()]
+fn triangle_mesh() {
+    let y = drop_sphere(quad(), 0.0);
+    assert!((y - 1.0).abs() < 0.1);
+
+    let y = drop_sphere(quad(), 15.0);
+    assert!(y < -10.0);
+}
+
+#[test()]
+fn scaled_triangle_mesh() {
+    let mesh = quad();
+    let scaled = Shape::new_scaled_triangle_mesh(&mesh, Vector3::new(2.0, 1.0, 2.0));
+    drop(mesh);
+
+    let y = drop_sphere(scaled, 15.0);
+    assert!((y - 1.0).abs() < 0.1);
+}
@@ -0,0 +1,60 @@
+extern crate bulletrs;
+extern crate cgmath;
+
+use cgmath::{Vector3, Vector4};
+
+use bulletrs::*;
+
+fn identity() -> Vector4<f64> {
+    Vector4::new(0.0, 0.0, 0.0, 1.0)
+}
+
+#[test()]
+fn box_to_triangle_mesh() {
+    let shape = Shape::new_box(Vector3::new(1.0, 1.0, 1.0));
+    let (vertices, indices) = shape.to_triangle_mesh(Vector3::new(10.0, 0.0, 0.0), identity());
+
+    assert!(indices.len() >= 12 * 3);
+    assert_eq!(indices.len() % 3, 0);
+    for index in indices.iter() {
+        assert!((*index as usize) < vertices.len());
+    }
+    for vertex in vertices.iter() {
+        assert!(vertex.x > 8.0 && vertex.x < 12.0);
+    }
+}
+
+#[test()]
+fn every_shape_to_triangle_mesh() {
+    let shapes = vec![
+        Shape::new_sphere(1.0),
+        Shape::new_capsule(0.5, 1.0, CapsuleAxis::Y),
+        Shape::new_cylinder(Vector3::new(1.0, 1.0, 1.0), CapsuleAxis::Z),
+        Shape::new_plane(Vector3::new(0.0, 1.0, 0.0), 0.0),
+        Shape::new_convex_hull(&[
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+            Vector3::new(0.0, 0.0, 1.0),
+        ]),
+        Shape::new_triangle_mesh(
+            &[
+                Vector3::new(0.0, 0.0, 0.0),
+                Vector3::new(1.0, 0.0, 0.0),
+                Vector3::new(0.0, 0.0, 1.0),
+            ],
+            &[0, 1, 2],
+        ),
+        Shape::new_compound(vec![
+            (Shape::new_sphere(1.0), Vector3::new(-2.0, 0.0, 0.0), identity()),
+            (Shape::new_sphere(1.0), Vector3::new(2.0, 0.0, 0.0), identity()),
+        ]),
+    ];
+
+    for shape in shapes.iter() {
+        let (vertices, indices) = shape.to_triangle_mesh(Vector3::new(0.0, 0.0, 0.0), identity());
+        assert!(!vertices.is_empty());
+        assert!(!indices.is_empty());
+        assert_eq!(indices.len() % 3, 0);
+    }
+}
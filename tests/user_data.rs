@@ -2,6 +2,7 @@ extern crate bulletrs;
 extern crate cgmath;
 
 use cgmath::{Vector3, Vector4};
+use std::rc::Rc;
 
 use bulletrs::*;
 
@@ -25,9 +26,9 @@ fn set_get_user_index() {
         Vector3::new(-4.0, 0.0, 0.0),
         Vector4::new(0.0, 0.0, 0.0, 1.0),
     ));
-    assert_eq!(body1.get_user_index(), -1);
-    body1.set_user_index(5);
-    assert_eq!(body1.get_user_index(), 5);
+    assert_eq!(body1.get_user_index().unwrap(), -1);
+    body1.set_user_index(5).unwrap();
+    assert_eq!(body1.get_user_index().unwrap(), 5);
 
 }
 
@@ -51,8 +52,63 @@ fn set_get_user_data() {
         Vector3::new(-4.0, 0.0, 0.0),
         Vector4::new(0.0, 0.0, 0.0, 1.0),
     ));
-    body1.set_user_data(5);
-    assert_eq!(*unsafe { body1.get_user_data::<i32>().unwrap() }, 5);
+    body1.set_user_data(5).unwrap();
+    assert_eq!(*body1.get_user_data::<i32>().unwrap().unwrap(), 5);
+    assert!(body1.get_user_data::<f64>().unwrap().is_none());
+}
+
+#[test()]
+fn user_data_dropped() {
+    let configuration = CollisionConfiguration::new_default();
+
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    let shape = Shape::new_sphere(1.0);
+    let mass = 0.1;
+    let mut body1 = dynamics_world.add_rigid_body(RigidBody::new(
+        mass,
+        shape.calculate_local_inertia(mass),
+        shape,
+        Vector3::new(-4.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+
+    let data = Rc::new(5);
+    body1.set_user_data(data.clone()).unwrap();
+    assert_eq!(Rc::strong_count(&data), 2);
+
+    body1.set_user_data(data.clone()).unwrap();
+    assert_eq!(Rc::strong_count(&data), 2);
+
+    assert!(body1.take_user_data::<i32>().unwrap().is_none());
+    let taken = body1.take_user_data::<Rc<i32>>().unwrap().unwrap();
+    assert_eq!(*taken, 5);
+    assert!(body1.get_user_data::<Rc<i32>>().unwrap().is_none());
+    drop(taken);
+    assert_eq!(Rc::strong_count(&data), 1);
+
+    body1.set_user_data(data.clone()).unwrap();
+    dynamics_world.remove_body(&body1).unwrap();
+    assert_eq!(Rc::strong_count(&data), 1);
+    assert_eq!(body1.set_user_data(data.clone()), Err(Error::BodyDeleted));
+    assert_eq!(Rc::strong_count(&data), 1);
+
+    let shape = Shape::new_sphere(1.0);
+    let mut body2 = dynamics_world.add_rigid_body(RigidBody::new(
+        mass,
+        shape.calculate_local_inertia(mass),
+        shape,
+        Vector3::new(4.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    body2.set_user_data(data.clone()).unwrap();
+    drop(dynamics_world);
+    assert_eq!(Rc::strong_count(&data), 1);
 }
 
 #[test()]
@@ -75,7 +131,7 @@ fn ray_test_user_data() {
         Vector3::new(-4.0, 0.0, 0.0),
         Vector4::new(0.0, 0.0, 0.0, 1.0),
     ));
-    body1.set_user_data(5);
+    body1.set_user_data(5).unwrap();
 
     let shape2 = Shape::new_sphere(2.0);
     let mass = 0.1;
@@ -86,7 +142,7 @@ fn ray_test_user_data() {
         Vector3::new(4.0, 0.0, 0.0),
         Vector4::new(0.0, 0.0, 0.0, 1.0),
     ));
-    body2.set_user_data(8);
+    body2.set_user_data(8).unwrap();
 
     let result = dynamics_world.raytest(ClosestRayResultCallback::new(
         Vector3::new(-10.0, 0.0, 0.0),
@@ -97,7 +153,7 @@ fn ray_test_user_data() {
     assert_eq!(result.intersections().len(), 1);
     let body = result.intersections()[0].rigidbody();
     assert!(body.is_some());
-    assert_eq!(*unsafe { body.unwrap().get_user_data::<i32>().unwrap() }, 5);
+    assert_eq!(*body.unwrap().get_user_data::<i32>().unwrap().unwrap(), 5);
 
     let result = dynamics_world.raytest(AllRayResultCallback::new(
         Vector3::new(-10.0, 0.0, 0.0),
@@ -106,3 +162,46 @@ fn ray_test_user_data() {
 
     assert_eq!(result.intersections().len(), 2);
 }
+
+#[test()]
+fn user_data_kept_while_borrowed() {
+    let configuration = CollisionConfiguration::new_default();
+
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    let shape = Shape::new_sphere(1.0);
+    let mass = 0.1;
+    let mut body1 = dynamics_world.add_rigid_body(RigidBody::new(
+        mass,
+        shape.calculate_local_inertia(mass),
+        shape,
+        Vector3::new(-4.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    body1.set_user_data(String::from("first")).unwrap();
+
+    let mut body2 = body1.clone();
+    let data = body1.get_user_data::<String>().unwrap().unwrap();
+    assert_eq!(
+        body2.set_user_data(String::from("second")),
+        Err(Error::BodiesBorrowed)
+    );
+    assert_eq!(body2.take_user_data::<String>(), Err(Error::BodiesBorrowed));
+    assert_eq!(*data, "first");
+    drop(data);
+
+    body2.set_user_data(String::from("second")).unwrap();
+    let data = body1.get_user_data::<String>().unwrap().unwrap();
+    assert_eq!(*data, "second");
+
+    // the data is kept until the borrow ends
+    drop(dynamics_world);
+    assert_eq!(*data, "second");
+    drop(data);
+    assert!(body1.removed());
+}
@@ -0,0 +1,135 @@
+extern crate bulletrs;
+extern crate cgmath;
+
+use cgmath::{Vector3, Vector4};
+
+use bulletrs::*;
+
+#[test()]
+fn iterate_bodies() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    assert_eq!(dynamics_world.body_count(), 0);
+    assert_eq!(dynamics_world.bodies().count(), 0);
+
+    let shape = Shape::new_sphere(1.0);
+    let mut body = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    body.set_user_index(1).unwrap();
+    let shape = Shape::new_sphere(1.0);
+    let mut handle = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(5.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    handle.set_user_index(2).unwrap();
+    let shape = Shape::new_sphere(1.0);
+    let mut body = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(10.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    body.set_user_index(3).unwrap();
+    assert_eq!(dynamics_world.body_count(), 3);
+
+    dynamics_world.remove_body(&body).unwrap();
+    assert_eq!(dynamics_world.body_count(), 2);
+
+    let mut user_indices = dynamics_world
+        .bodies()
+        .map(|body| body.get_user_index().unwrap())
+        .collect::<Vec<_>>();
+    user_indices.sort();
+    assert_eq!(user_indices, vec![2, 3]);
+
+    let mut count = 0;
+    let mut bodies = dynamics_world.bodies_mut();
+    while let Some(body) = bodies.next_body() {
+        assert_eq!(body.body_type(), BodyType::Dynamic);
+        assert_eq!(handle.get_user_index(), Err(Error::BodiesBorrowed));
+        count += 1;
+    }
+    assert_eq!(count, 2);
+    drop(bodies);
+    assert_eq!(handle.get_user_index(), Ok(2));
+}
+
+#[test()]
+fn find_by_user_index() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    let shape = Shape::new_sphere(1.0);
+    let mut body = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    body.set_user_index(1).unwrap();
+    let shape = Shape::new_sphere(1.0);
+    let mut body = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(5.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    body.set_user_index(2).unwrap();
+
+    let body = dynamics_world.body_by_user_index(2).unwrap();
+    let (position, _) = body.get_world_position_and_orientation().unwrap();
+    assert_eq!(position.x, 5.0);
+    assert!(dynamics_world.body_by_user_index(3).is_none());
+}
+
+#[test()]
+fn handle_from_collision_object() {
+    let configuration = CollisionConfiguration::new_default();
+    let mut dynamics_world = DynamicsWorld::new_discrete_world(
+        CollisionDispatcher::new(&configuration),
+        Broadphase::new(BroadphaseInterface::DbvtBroadphase),
+        ConstraintSolver::new(),
+        configuration,
+    );
+
+    let shape = Shape::new_sphere(1.0);
+    let mut body = dynamics_world.add_rigid_body(RigidBody::new(
+        1.0,
+        shape.calculate_local_inertia(1.0),
+        shape,
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ));
+    body.set_user_index(1).unwrap();
+    let collision_object = unsafe { body.ptr().unwrap() } as *const sys::btCollisionObject;
+
+    let found = dynamics_world.handle_from_collision_object(collision_object).unwrap();
+    assert_eq!(found.get_user_index(), Ok(1));
+
+    assert!(dynamics_world.handle_from_collision_object(::std::ptr::null()).is_none());
+
+    dynamics_world.remove_body(&body).unwrap();
+    assert!(dynamics_world.handle_from_collision_object(collision_object).is_none());
+}